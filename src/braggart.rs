@@ -0,0 +1,117 @@
+use std::io;
+use std::io::Read;
+
+/// `Braggart` wraps any [std::io::Read] and reads from it, but always claims to have filled
+/// the whole buffer and then read `extra` bytes more, which can't possibly fit. An `extra` of
+/// `usize::MAX` means every read claims `usize::MAX` bytes, while an `extra` of zero claims
+/// just the whole buffer, which is only a lie when the read was short. Only the end of the
+/// stream is reported honestly.
+///
+/// [Read::read] promises never to do this, but it isn't an unsafe trait, so callers can't
+/// rely on that promise. Some of the standard library's provided methods, and
+/// [std::io::BufReader], panic when they notice.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Braggart;
+/// use std::io::Read;
+/// let mut braggart = Braggart::new(&b"Hello, World"[..], 3);
+/// let mut buffer = [0u8; 5];
+/// assert_eq!(braggart.read(&mut buffer).unwrap(), 8);
+/// assert_eq!(&buffer, b"Hello");
+/// ```
+///
+/// ```should_panic
+/// # use misfortunate::Braggart;
+/// use std::io::Read;
+/// let mut braggart = Braggart::new(&b"Hello, World"[..], usize::MAX);
+/// let mut buffer = Vec::new();
+/// let _ = braggart.read_to_end(&mut buffer); // Panics
+/// ```
+#[derive(Clone, Debug)]
+pub struct Braggart<R> {
+    inner: R,
+    extra: usize,
+}
+
+impl<R: Read> Braggart<R> {
+    /// Constructs a new `Braggart` around the `inner` reader, which exaggerates by `extra` bytes
+    pub fn new(inner: R, extra: usize) -> Braggart<R> {
+        Braggart { inner, extra }
+    }
+}
+
+impl<R: Read> Read for Braggart<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.inner.read(buf)? {
+            0 => Ok(0),
+            _ => Ok(buf.len().saturating_add(self.extra)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::panic::catch_unwind;
+
+    #[test]
+    fn create() {
+        let _ = Braggart::new(&b"Hello"[..], 1);
+    }
+
+    #[test]
+    fn modest() {
+        let mut braggart = Braggart::new(&b"Hello"[..], 0);
+        let mut buffer = [0u8; 5];
+        assert_eq!(braggart.read(&mut buffer).unwrap(), 5);
+        assert_eq!(&buffer, b"Hello");
+        assert_eq!(braggart.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn short() {
+        // Even a short read claims more than the whole buffer
+        let mut braggart = Braggart::new(&b"Hi"[..], 1);
+        let mut buffer = [0u8; 64];
+        assert_eq!(braggart.read(&mut buffer).unwrap(), 65);
+        assert_eq!(&buffer[..2], b"Hi");
+    }
+
+    #[test]
+    fn exaggerates() {
+        let mut braggart = Braggart::new(&b"Hello"[..], usize::MAX);
+        let mut buffer = [0u8; 16];
+        assert_eq!(braggart.read(&mut buffer).unwrap(), usize::MAX);
+        assert_eq!(&buffer[..5], b"Hello");
+        assert_eq!(braggart.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn std_panics() {
+        let result = catch_unwind(|| {
+            let mut buffer = [0u8; 4];
+            Braggart::new(&b"Hello"[..], 1).read_exact(&mut buffer)
+        });
+        assert!(result.is_err());
+        let result = catch_unwind(|| {
+            let mut buffer = Vec::new();
+            Braggart::new(&b"Hello"[..], usize::MAX).read_to_end(&mut buffer)
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn believed() {
+        // Claiming just the whole buffer fits, so a short read is believed
+        let mut reader = BufReader::with_capacity(8, Braggart::new(&b"Hello"[..], 0));
+        assert_eq!(reader.fill_buf().unwrap(), b"Hello\0\0\0");
+        let result = catch_unwind(|| {
+            let mut reader = BufReader::new(Braggart::new(&b"Hello"[..], 1));
+            reader.fill_buf().map(<[u8]>::len)
+        });
+        assert!(result.is_err());
+    }
+}
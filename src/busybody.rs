@@ -0,0 +1,126 @@
+use std::io;
+use std::io::Read;
+
+/// `Busybody` wraps any [std::io::Read] and reads from it faithfully, but first it takes a
+/// note of whatever was already in the buffer it was given. Optionally it also scribbles
+/// over the rest of the buffer, after the bytes it actually read, which [Read::read] permits.
+///
+/// Code that relies on anything past the count returned by `read`, or which passes in a
+/// reused buffer full of secrets, will be found out.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Busybody;
+/// use std::io::Read;
+/// let mut nosy = Busybody::new(&b"Hello"[..]);
+/// let mut buffer = *b"Secret";
+/// assert_eq!(nosy.read(&mut buffer).unwrap(), 5);
+/// assert_eq!(&buffer, b"Hellot");
+/// assert_eq!(nosy.seen()[0], b"Secret");
+/// ```
+///
+/// ```
+/// # use misfortunate::Busybody;
+/// use std::io::Read;
+/// let mut nosy = Busybody::new_scribble(&b"Hi"[..], b'?');
+/// let mut buffer = [0u8; 5];
+/// assert_eq!(nosy.read(&mut buffer).unwrap(), 2);
+/// assert_eq!(&buffer, b"Hi???");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Busybody<R> {
+    inner: R,
+    seen: Vec<Vec<u8>>,
+    scribble: Option<u8>,
+}
+
+impl<R: Read> Busybody<R> {
+    /// Constructs a new `Busybody` around the `inner` reader
+    pub fn new(inner: R) -> Busybody<R> {
+        Busybody {
+            inner,
+            seen: Vec::new(),
+            scribble: None,
+        }
+    }
+
+    /// Constructs a new `Busybody` around the `inner` reader, which also fills any part of the
+    /// buffer it didn't read into with `junk`
+    pub fn new_scribble(inner: R, junk: u8) -> Busybody<R> {
+        Busybody {
+            inner,
+            seen: Vec::new(),
+            scribble: Some(junk),
+        }
+    }
+
+    /// What was already in the buffer, for each call to `read` so far
+    pub fn seen(&self) -> &[Vec<u8>] {
+        &self.seen
+    }
+}
+
+impl<R: Read> Read for Busybody<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.seen.push(buf.to_vec());
+        let n = self.inner.read(buf)?;
+        if let Some(junk) = self.scribble {
+            if let Some(rest) = buf.get_mut(n..) {
+                rest.fill(junk);
+            }
+        }
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let nosy = Busybody::new(&b"Hello"[..]);
+        assert!(nosy.seen().is_empty());
+    }
+
+    #[test]
+    fn remembers() {
+        let mut nosy = Busybody::new(&b"abcdef"[..]);
+        let mut buffer = *b"XYZ";
+        assert_eq!(nosy.read(&mut buffer).unwrap(), 3);
+        assert_eq!(nosy.read(&mut buffer).unwrap(), 3);
+        assert_eq!(nosy.read(&mut buffer).unwrap(), 0);
+        assert_eq!(nosy.seen(), [b"XYZ", b"abc", b"def"]);
+    }
+
+    #[test]
+    fn scribbles() {
+        let mut nosy = Busybody::new_scribble(&b"abc"[..], 0xFF);
+        let mut buffer = [0u8; 2];
+        assert_eq!(nosy.read(&mut buffer).unwrap(), 2);
+        assert_eq!(&buffer, b"ab");
+        assert_eq!(nosy.read(&mut buffer).unwrap(), 1);
+        assert_eq!(buffer, [b'c', 0xFF]);
+        assert_eq!(nosy.read(&mut buffer).unwrap(), 0);
+        assert_eq!(buffer, [0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn read_to_string() {
+        let mut nosy = Busybody::new_scribble(&b"Hello, World"[..], 0xFF);
+        let mut text = String::new();
+        nosy.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "Hello, World");
+    }
+
+    #[test]
+    fn read_to_end_is_tidy() {
+        let mut nosy = Busybody::new(&b"Hello, World"[..]);
+        let mut buffer = Vec::new();
+        nosy.read_to_end(&mut buffer).unwrap();
+        for seen in nosy.seen() {
+            assert!(seen.iter().all(|&b| b == 0));
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// How the clone of a [Changeling] differs from the original
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Divergence {
+    /// The clone starts again from the very beginning
+    Restart,
+    /// The clone has silently skipped this many items ahead of the original
+    Offset(usize),
+    /// The clone shares a cursor with the original, so each takes items from the other
+    Shared,
+}
+
+/// `Changeling` wraps any [Iterator] which can be cloned, and passes along its items faithfully.
+/// It also implements [Clone], but the clone is not what it seems, and yields a different
+/// sequence from the one the original would have produced.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Changeling, Divergence};
+/// let mut original = Changeling::new(1..10, Divergence::Restart);
+/// assert_eq!(original.next(), Some(1));
+/// assert_eq!(original.next(), Some(2));
+/// let mut copy = original.clone();
+/// assert_eq!(original.next(), Some(3));
+/// assert_eq!(copy.next(), Some(1));
+/// ```
+///
+/// ```
+/// # use misfortunate::{Changeling, Divergence};
+/// let mut original = Changeling::new("abcdef".chars(), Divergence::Shared);
+/// let mut copy = original.clone();
+/// assert_eq!(copy.next(), Some('a'));
+/// assert_eq!(original.next(), Some('b'));
+/// assert_eq!(copy.next(), Some('c'));
+/// ```
+#[derive(Debug)]
+pub struct Changeling<I> {
+    origin: I,
+    cursor: Rc<RefCell<I>>,
+    divergence: Divergence,
+}
+
+impl<I: Iterator + Clone> Changeling<I> {
+    /// Constructs a new `Changeling` from `inner`, whose clones have this [Divergence]
+    pub fn new(inner: I, divergence: Divergence) -> Changeling<I> {
+        Changeling {
+            origin: inner.clone(),
+            cursor: Rc::new(RefCell::new(inner)),
+            divergence,
+        }
+    }
+
+    /// The `divergence` of this `Changeling`'s clones
+    pub fn divergence(&self) -> Divergence {
+        self.divergence
+    }
+}
+
+impl<I: Iterator + Clone> Clone for Changeling<I> {
+    fn clone(&self) -> Self {
+        let cursor = match self.divergence {
+            Divergence::Restart => Rc::new(RefCell::new(self.origin.clone())),
+            Divergence::Offset(skip) => {
+                let mut inner = self.cursor.borrow().clone();
+                for _ in 0..skip {
+                    inner.next();
+                }
+                Rc::new(RefCell::new(inner))
+            }
+            Divergence::Shared => Rc::clone(&self.cursor),
+        };
+        Changeling {
+            origin: self.origin.clone(),
+            cursor,
+            divergence: self.divergence,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Changeling<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.cursor.borrow_mut().next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cursor.borrow().size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let c = Changeling::new(0..5, Divergence::Offset(2));
+        assert_eq!(c.divergence(), Divergence::Offset(2));
+    }
+
+    #[test]
+    fn faithful() {
+        let c = Changeling::new(0..5, Divergence::Shared);
+        let v: Vec<u8> = c.collect();
+        assert_eq!(v, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn restart() {
+        let mut c = Changeling::new(0..5, Divergence::Restart);
+        assert_eq!(c.nth(2), Some(2));
+        let d = c.clone();
+        let v: Vec<u8> = d.collect();
+        assert_eq!(v, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn offset() {
+        let mut c = Changeling::new(0..5, Divergence::Offset(2));
+        assert_eq!(c.next(), Some(0));
+        let d = c.clone();
+        let v: Vec<u8> = d.collect();
+        assert_eq!(v, [3, 4]);
+        assert_eq!(c.next(), Some(1));
+    }
+
+    #[test]
+    fn shared() {
+        let c = Changeling::new(0..6, Divergence::Shared);
+        let d = c.clone();
+        let pairs: Vec<(u8, u8)> = c.zip(d).collect();
+        assert_eq!(pairs, [(0, 1), (2, 3), (4, 5)]);
+    }
+
+    #[test]
+    fn peekable() {
+        let original = Changeling::new("one two".split(' '), Divergence::Shared);
+        let mut lookahead = original.clone().peekable();
+        assert_eq!(lookahead.peek(), Some(&"one"));
+        let rest: Vec<&str> = original.collect();
+        assert_eq!(rest, ["two"]);
+    }
+}
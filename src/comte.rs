@@ -1,8 +1,14 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
 /// `Comte` claims to be an [ExactSizeIterator].
 /// At first Comte claims to be empty and if iterated returns None.
 /// However, Comte is not fused and after a `tap` it will subsequently give
 /// back cloned `rabbit`s forever.
 ///
+/// A Comte made `with_wand` can also be tapped, or un-tapped, using its [Wand], even while
+/// some other code is busy iterating over it.
+///
 /// Louis Apollinaire Christien Emmanuel Comte was a magician who may have performed the first hat-trick
 ///
 /// # Examples
@@ -16,10 +22,60 @@
 /// assert_eq!(hat.next(), Some("Rabbit"));
 /// assert_eq!(hat.next(), Some("Rabbit"));
 /// ```
+///
+/// ```
+/// # use misfortunate::Comte;
+/// let (hat, wand) = Comte::with_wand("Rabbit");
+/// wand.tap();
+/// let mut rabbits = 0;
+/// for _ in hat {
+///     rabbits += 1;
+///     if rabbits == 3 {
+///         wand.untap();
+///     }
+/// }
+/// assert_eq!(rabbits, 3);
+/// ```
 #[derive(Clone, Debug)]
 pub struct Comte<T> {
     rabbit: T,
     revealed: bool,
+    wand: Option<Wand>,
+}
+
+/// A `Wand` taps (or un-taps) the [Comte] it was made with, from outside.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Comte;
+/// let (mut hat, wand) = Comte::with_wand("Rabbit");
+/// assert_eq!(hat.next(), None);
+/// wand.tap();
+/// assert_eq!(hat.next(), Some("Rabbit"));
+/// wand.untap();
+/// assert_eq!(hat.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Wand {
+    revealed: Arc<AtomicBool>,
+}
+
+impl Wand {
+    /// The `rabbit` is now revealed
+    pub fn tap(&self) {
+        self.revealed.store(true, Ordering::Relaxed);
+    }
+
+    /// The `rabbit` is hidden once more
+    pub fn untap(&self) {
+        self.revealed.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether the `rabbit` is currently revealed
+    pub fn is_tapped(&self) -> bool {
+        self.revealed.load(Ordering::Relaxed)
+    }
 }
 
 impl<T> Comte<T> {
@@ -28,19 +84,44 @@ impl<T> Comte<T> {
         Comte {
             rabbit,
             revealed: false,
+            wand: None,
         }
     }
 
-    /// Irreversible. Previously empty iterator now produces cloned `rabbit`s
+    /// Constructs a new `Comte` with a concealed `rabbit`, and a [Wand] to tap it with
+    pub fn with_wand(rabbit: T) -> (Comte<T>, Wand) {
+        let wand = Wand {
+            revealed: Arc::new(AtomicBool::new(false)),
+        };
+        let comte = Comte {
+            rabbit,
+            revealed: false,
+            wand: Some(wand.clone()),
+        };
+        (comte, wand)
+    }
+
+    /// Irreversible, unless this `Comte` has a [Wand].
+    /// Previously empty iterator now produces cloned `rabbit`s
     pub fn tap(&mut self) {
-        self.revealed = true;
+        match &self.wand {
+            Some(wand) => wand.tap(),
+            None => self.revealed = true,
+        }
+    }
+
+    fn revealed(&self) -> bool {
+        match &self.wand {
+            Some(wand) => wand.is_tapped(),
+            None => self.revealed,
+        }
     }
 }
 
 impl<T: Clone> Iterator for Comte<T> {
     type Item = T;
     fn next(&mut self) -> Option<T> {
-        if self.revealed {
+        if self.revealed() {
             Some(self.rabbit.clone())
         } else {
             None
@@ -48,7 +129,7 @@ impl<T: Clone> Iterator for Comte<T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.revealed {
+        if self.revealed() {
             (usize::MAX, None)
         } else {
             (0, Some(0))
@@ -110,4 +191,40 @@ mod tests {
         assert_eq!(hat.next(), Some("Rabbit"));
         assert_ne!(hat.len(), 0); // This panics because ExactSizeIterators shouldn't have infinite size
     }
+
+    #[test]
+    fn wand() {
+        let (mut hat, wand) = Comte::with_wand("Rabbit");
+        assert!(!wand.is_tapped());
+        assert_eq!(hat.next(), None);
+        hat.tap();
+        assert!(wand.is_tapped());
+        assert_eq!(hat.next(), Some("Rabbit"));
+        wand.untap();
+        assert_eq!(hat.next(), None);
+        assert_eq!(hat.len(), 0);
+    }
+
+    #[test]
+    fn mid_extend() {
+        let (hat, wand) = Comte::with_wand(7u8);
+        wand.tap();
+        let mut v = Vec::new();
+        v.extend(hat.inspect(|_| wand.untap()));
+        assert_eq!(v, [7]);
+    }
+
+    #[test]
+    fn mid_zip() {
+        let (hat, wand) = Comte::with_wand('x');
+        wand.tap();
+        let pairs: Vec<(char, u8)> = hat
+            .zip((0..5).inspect(|&n| {
+                if n == 2 {
+                    wand.untap();
+                }
+            }))
+            .collect();
+        assert_eq!(pairs, [('x', 0), ('x', 1), ('x', 2)]);
+    }
 }
@@ -0,0 +1,225 @@
+use crate::prng::Prng;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::io;
+use std::io::{Error, ErrorKind, Read, Write};
+use std::rc::Rc;
+
+#[derive(Clone, Debug)]
+enum Schedule {
+    Naps(VecDeque<usize>),
+    Random { prng: Prng, one_in: u32 },
+}
+
+impl Schedule {
+    fn next(&mut self) -> usize {
+        match self {
+            Schedule::Naps(naps) => naps.pop_front().unwrap_or(0),
+            Schedule::Random { prng, one_in } => usize::from(prng.one_in(*one_in)),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Drowsiness {
+    naps: Cell<usize>,
+    blocked: Cell<usize>,
+}
+
+/// `Alarm` shows whether a [Dormouse] is awake, so that a test's poll loop can tell when
+/// progress is possible without trying it.
+#[derive(Clone, Debug)]
+pub struct Alarm {
+    drowsiness: Rc<Drowsiness>,
+}
+
+impl Alarm {
+    /// Whether the next call to the Dormouse will reach the inner stream rather than being
+    /// told [ErrorKind::WouldBlock]
+    pub fn is_ready(&self) -> bool {
+        self.drowsiness.naps.get() == 0
+    }
+
+    /// How many calls the Dormouse has answered with [ErrorKind::WouldBlock] so far
+    pub fn blocked(&self) -> usize {
+        self.drowsiness.blocked.get()
+    }
+}
+
+/// `Dormouse` wraps any [std::io::Read] or [std::io::Write] and behaves like a nonblocking
+/// socket, often answering `read`, `write` or `flush` with [ErrorKind::WouldBlock] because it
+/// is asleep. Each such call uses up one nap, and once the Dormouse is awake, the next call
+/// reaches the `inner` stream. An [Alarm] shows whether it is awake.
+///
+/// The naps before each successful call are taken either from a list, after which the
+/// Dormouse stays awake, or decided at random with a chance of one in `one_in` before each
+/// call. Unlike a [LoadLetter](crate::LoadLetter) with [ErrorKind::WouldBlock] it always
+/// wakes up eventually, unless `one_in` is one.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Dormouse;
+/// use std::io::{ErrorKind, Read};
+/// let mut dormouse = Dormouse::new(&b"Twinkle, twinkle"[..], [2, 0, 1]);
+/// let alarm = dormouse.alarm();
+/// let mut buffer = [0u8; 8];
+/// let mut reads = Vec::new();
+/// while reads.len() < 3 {
+///     if !alarm.is_ready() {
+///         let err = dormouse.read(&mut buffer).err().unwrap();
+///         assert_eq!(err.kind(), ErrorKind::WouldBlock);
+///         continue;
+///     }
+///     let n = dormouse.read(&mut buffer).unwrap();
+///     reads.push(buffer[..n].to_vec());
+/// }
+/// assert_eq!(reads, [&b"Twinkle,"[..], b" twinkle", b""]);
+/// assert_eq!(alarm.blocked(), 3);
+/// ```
+#[derive(Debug)]
+pub struct Dormouse<T> {
+    inner: T,
+    schedule: Schedule,
+    drowsiness: Rc<Drowsiness>,
+}
+
+impl<T> Dormouse<T> {
+    /// Constructs a new `Dormouse` around `inner`, which sleeps through the number of calls
+    /// given by each of the `naps` before each successful call, and then stays awake
+    pub fn new<I: IntoIterator<Item = usize>>(inner: T, naps: I) -> Dormouse<T> {
+        let schedule = Schedule::Naps(naps.into_iter().collect());
+        Self::with_schedule(inner, schedule)
+    }
+
+    /// Constructs a new `Dormouse` around `inner` with a chance of one in `one_in` that it is
+    /// asleep for each call. The same `seed` always sleeps the same way.
+    pub fn new_random(inner: T, seed: u64, one_in: u32) -> Dormouse<T> {
+        let prng = Prng::new(seed);
+        Self::with_schedule(inner, Schedule::Random { prng, one_in })
+    }
+
+    fn with_schedule(inner: T, mut schedule: Schedule) -> Dormouse<T> {
+        let drowsiness = Rc::new(Drowsiness::default());
+        drowsiness.naps.set(schedule.next());
+        Dormouse {
+            inner,
+            schedule,
+            drowsiness,
+        }
+    }
+
+    /// An [Alarm] showing whether this `Dormouse` is awake
+    pub fn alarm(&self) -> Alarm {
+        Alarm {
+            drowsiness: self.drowsiness.clone(),
+        }
+    }
+
+    /// The `inner` stream
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    fn doze<U>(&mut self, call: impl FnOnce(&mut T) -> io::Result<U>) -> io::Result<U> {
+        let drowsiness = &self.drowsiness;
+        let naps = drowsiness.naps.get();
+        if naps > 0 {
+            drowsiness.blocked.set(drowsiness.blocked.get() + 1);
+            let naps = match self.schedule {
+                Schedule::Naps(_) => naps - 1,
+                Schedule::Random { .. } => self.schedule.next(),
+            };
+            drowsiness.naps.set(naps);
+            return Err(Error::from(ErrorKind::WouldBlock));
+        }
+        drowsiness.naps.set(self.schedule.next());
+        call(&mut self.inner)
+    }
+}
+
+impl<T: Read> Read for Dormouse<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.doze(|inner| inner.read(buf))
+    }
+}
+
+impl<T: Write> Write for Dormouse<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.doze(|inner| inner.write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.doze(|inner| inner.flush())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let dormouse = Dormouse::new(Vec::<u8>::new(), []);
+        assert!(dormouse.alarm().is_ready());
+        assert!(dormouse.inner().is_empty());
+    }
+
+    #[test]
+    fn naps() {
+        let mut dormouse = Dormouse::new(Vec::new(), [1, 2]);
+        let alarm = dormouse.alarm();
+        assert!(!alarm.is_ready());
+        let err = dormouse.write(b"Hello").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::WouldBlock);
+        assert!(alarm.is_ready());
+        assert_eq!(dormouse.write(b"Hello").unwrap(), 5);
+        assert!(!alarm.is_ready());
+        assert!(dormouse.flush().is_err());
+        assert!(dormouse.flush().is_err());
+        dormouse.flush().unwrap();
+        assert!(alarm.is_ready());
+        assert_eq!(alarm.blocked(), 3);
+        assert_eq!(dormouse.inner(), b"Hello");
+    }
+
+    #[test]
+    fn write_all() {
+        // write_all retries Interrupted, but not WouldBlock
+        let mut dormouse = Dormouse::new(Vec::new(), [0, 1]);
+        let err = dormouse.write_all(b"Hello").err();
+        assert!(err.is_none());
+        let err = dormouse.write_all(b"World").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::WouldBlock);
+    }
+
+    #[test]
+    fn random() {
+        let mut dormouse = Dormouse::new_random(&b"The quick brown fox"[..], 1234, 2);
+        let alarm = dormouse.alarm();
+        let mut text = Vec::new();
+        let mut buffer = [0u8; 4];
+        loop {
+            let ready = alarm.is_ready();
+            match dormouse.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => text.extend_from_slice(&buffer[..n]),
+                Err(e) => {
+                    assert!(!ready);
+                    assert_eq!(e.kind(), ErrorKind::WouldBlock);
+                }
+            }
+        }
+        assert_eq!(text, b"The quick brown fox");
+        assert!(alarm.blocked() > 0);
+    }
+
+    #[test]
+    fn repeatable() {
+        let pattern = |seed| {
+            let mut dormouse = Dormouse::new_random(io::sink(), seed, 3);
+            (0..50).map(|_| dormouse.write(b"x").is_ok()).collect::<Vec<_>>()
+        };
+        assert_eq!(pattern(5), pattern(5));
+    }
+}
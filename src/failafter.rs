@@ -0,0 +1,154 @@
+use crate::LoadLetter;
+use std::io;
+use std::io::{Cursor, Read, Write};
+
+/// `FailAfter` implements [std::io::Read] and [std::io::Write] honestly, reading from its
+/// `input` and keeping everything written to it, but only until a fixed `budget` of bytes
+/// (shared between reading and writing) is spent. After that every operation fails in the
+/// same way as its [LoadLetter].
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{FailAfter, LoadLetter};
+/// use std::io::{ErrorKind, Write};
+/// let mut fa = FailAfter::new(Vec::new(), 5, LoadLetter::new(ErrorKind::StorageFull));
+/// let err = fa.write_all(b"Hello, World").err().unwrap();
+/// assert_eq!(err.kind(), ErrorKind::StorageFull);
+/// assert_eq!(fa.written(), b"Hello");
+/// ```
+///
+/// ```
+/// # use misfortunate::{FailAfter, LoadLetter};
+/// use std::io::{self, ErrorKind};
+/// let mut fa = FailAfter::new(b"Hello, World".to_vec(), 8, Default::default());
+/// let mut copy = Vec::new();
+/// let err = io::copy(&mut fa, &mut copy).err().unwrap();
+/// assert_eq!(err.to_string(), "PC Load Letter");
+/// assert_eq!(copy, b"Hello, W");
+/// ```
+#[derive(Clone, Debug)]
+pub struct FailAfter<'a> {
+    input: Cursor<Vec<u8>>,
+    output: Vec<u8>,
+    budget: usize,
+    letter: LoadLetter<'a>,
+}
+
+impl<'a> FailAfter<'a> {
+    /// Constructs a new `FailAfter` which reads `input`, and then fails like `letter` once
+    /// `budget` bytes have been read or written
+    pub fn new(input: Vec<u8>, budget: usize, letter: LoadLetter<'a>) -> Self {
+        Self {
+            input: Cursor::new(input),
+            output: Vec::new(),
+            budget,
+            letter,
+        }
+    }
+
+    /// Everything successfully written so far
+    pub fn written(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// How many more bytes can be read or written before this `FailAfter` fails
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+}
+
+impl Read for FailAfter<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.budget == 0 {
+            return Err(self.letter.error());
+        }
+        let limit = buf.len().min(self.budget);
+        let n = self.input.read(&mut buf[..limit])?;
+        self.budget -= n;
+        Ok(n)
+    }
+}
+
+impl Write for FailAfter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.budget == 0 {
+            return Err(self.letter.error());
+        }
+        let n = buf.len().min(self.budget);
+        self.output.extend_from_slice(&buf[..n]);
+        self.budget -= n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.budget == 0 {
+            return Err(self.letter.error());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufWriter, ErrorKind};
+
+    #[test]
+    fn create() {
+        let fa = FailAfter::new(Vec::new(), 42, Default::default());
+        assert_eq!(fa.budget(), 42);
+        assert!(fa.written().is_empty());
+    }
+
+    #[test]
+    fn reading() {
+        let mut fa = FailAfter::new(b"abcdef".to_vec(), 4, Default::default());
+        let mut buffer = [0u8; 3];
+        assert_eq!(fa.read(&mut buffer).unwrap(), 3);
+        assert_eq!(&buffer, b"abc");
+        assert_eq!(fa.read(&mut buffer).unwrap(), 1);
+        assert_eq!(&buffer[..1], b"d");
+        let result = fa.read(&mut buffer);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn short_input() {
+        let mut fa = FailAfter::new(b"ab".to_vec(), 4, Default::default());
+        let mut buffer = [0u8; 3];
+        assert_eq!(fa.read(&mut buffer).unwrap(), 2);
+        assert_eq!(fa.read(&mut buffer).unwrap(), 0);
+        assert_eq!(fa.budget(), 2);
+    }
+
+    #[test]
+    fn writing() {
+        let ll = LoadLetter::new(ErrorKind::BrokenPipe);
+        let mut fa = FailAfter::new(Vec::new(), 6, ll);
+        assert_eq!(fa.write(b"abcd").unwrap(), 4);
+        assert!(fa.flush().is_ok());
+        assert_eq!(fa.write(b"efgh").unwrap(), 2);
+        let err = fa.write(b"ijkl").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+        let err = fa.flush().err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+        assert_eq!(fa.written(), b"abcdef");
+    }
+
+    #[test]
+    fn buffered() {
+        let ll = LoadLetter::new(ErrorKind::ConnectionAborted);
+        let mut fa = FailAfter::new(Vec::new(), 100, ll);
+        let mut writer = BufWriter::with_capacity(64, &mut fa);
+        for _ in 0..10 {
+            writer.write_all(b"0123456789").unwrap();
+        }
+        let result = writer.write_all(b"0123456789");
+        let result = result.and_then(|()| writer.flush());
+        let err = result.err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ConnectionAborted);
+        drop(writer);
+        assert_eq!(fa.written().len(), 100);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::prng::Prng;
+use std::io;
+use std::io::Read;
+
+const EURO: &[u8] = "€".as_bytes();
+
+/// `Spring` decides what flows from a [Fountain]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Spring {
+    /// Zero bytes, forever
+    Zeros,
+    /// This pattern, repeated forever. An empty pattern gives zeros
+    Pattern(Vec<u8>),
+    /// Pseudo-random bytes. The same seed always gives the same bytes
+    Random(u64),
+    /// Valid UTF-8, "€€€…", but every read possible ends part way through a character
+    Split,
+    /// Invalid UTF-8. Each read is "€€…" starting afresh, and ending part way through a
+    /// character, so no read alone looks wrong until the next begins
+    Broken,
+}
+
+/// `Budget` is how many bytes a [Fountain] may produce, and what happens when a read asks
+/// for more after that
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Budget {
+    /// Panic, which a test harness can catch
+    Panic(usize),
+    /// Abort the process, for when even unwinding can't be trusted
+    Abort(usize),
+}
+
+/// `Fountain` implements [std::io::Read] by never running dry. Where a
+/// [BlackHole](crate::BlackHole) is always at the end of its stream, a Fountain never gets
+/// there, so anything that reads it to the end will try to read forever, or until it runs
+/// out of memory.
+///
+/// A [Budget] keeps this from getting out of hand, by panicking or aborting once a set
+/// number of bytes has flowed. The [Spring::Split] and [Spring::Broken] springs check that
+/// UTF-8 decoding copes with characters divided between reads.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Fountain, Spring};
+/// use std::io::Read;
+/// let fountain = Fountain::new(Spring::Pattern(b"ab".to_vec()));
+/// let mut text = String::new();
+/// fountain.take(5).read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "ababa");
+/// ```
+///
+/// ```should_panic
+/// # use misfortunate::{Budget, Fountain, Spring};
+/// use std::io::Read;
+/// let mut fountain = Fountain::with_budget(Spring::Zeros, Budget::Panic(1 << 20));
+/// let mut everything = Vec::new();
+/// fountain.read_to_end(&mut everything).unwrap(); // Panics
+/// ```
+#[derive(Clone, Debug)]
+pub struct Fountain {
+    spring: Spring,
+    prng: Prng,
+    flowed: usize,
+    budget: Option<Budget>,
+}
+
+impl Fountain {
+    /// Constructs a new `Fountain` fed by this `spring`, which flows forever
+    pub fn new(spring: Spring) -> Fountain {
+        let seed = match spring {
+            Spring::Random(seed) => seed,
+            _ => 0,
+        };
+        Fountain {
+            spring,
+            prng: Prng::new(seed),
+            flowed: 0,
+            budget: None,
+        }
+    }
+
+    /// Constructs a new `Fountain` fed by this `spring`, which panics or aborts when asked
+    /// for more than its `budget`
+    pub fn with_budget(spring: Spring, budget: Budget) -> Fountain {
+        Fountain {
+            budget: Some(budget),
+            ..Self::new(spring)
+        }
+    }
+
+    /// How many bytes have flowed so far
+    pub fn flowed(&self) -> usize {
+        self.flowed
+    }
+
+    fn allowance(&self, wanted: usize) -> usize {
+        let (limit, abort) = match self.budget {
+            None => return wanted,
+            Some(Budget::Panic(limit)) => (limit, false),
+            Some(Budget::Abort(limit)) => (limit, true),
+        };
+        let remaining = limit - self.flowed;
+        if wanted > 0 && remaining == 0 {
+            if abort {
+                std::process::abort();
+            }
+            panic!("Fountain ran past its budget of {limit} bytes");
+        }
+        wanted.min(remaining)
+    }
+}
+
+/// Shortens `n` if that would let a read starting at `offset` end on a character boundary
+fn split(offset: usize, n: usize) -> usize {
+    if n > 1 && (offset + n).is_multiple_of(EURO.len()) {
+        n - 1
+    } else {
+        n
+    }
+}
+
+impl Read for Fountain {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut n = self.allowance(buf.len());
+        let offset = self.flowed;
+        match &self.spring {
+            Spring::Zeros => buf[..n].fill(0),
+            Spring::Pattern(pattern) if pattern.is_empty() => buf[..n].fill(0),
+            Spring::Pattern(pattern) => {
+                for (i, byte) in buf[..n].iter_mut().enumerate() {
+                    *byte = pattern[(offset + i) % pattern.len()];
+                }
+            }
+            Spring::Random(_) => {
+                for byte in &mut buf[..n] {
+                    *byte = self.prng.next_u64() as u8;
+                }
+            }
+            Spring::Split => {
+                n = split(offset, n);
+                for (i, byte) in buf[..n].iter_mut().enumerate() {
+                    *byte = EURO[(offset + i) % EURO.len()];
+                }
+            }
+            Spring::Broken => {
+                n = split(0, n);
+                for (i, byte) in buf[..n].iter_mut().enumerate() {
+                    *byte = EURO[i % EURO.len()];
+                }
+            }
+        }
+        self.flowed += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::ErrorKind;
+    use std::panic::catch_unwind;
+
+    #[test]
+    fn create() {
+        let fountain = Fountain::new(Spring::Zeros);
+        assert_eq!(fountain.flowed(), 0);
+    }
+
+    #[test]
+    fn zeros() {
+        let mut fountain = Fountain::new(Spring::Pattern(Vec::new()));
+        let mut buffer = [42u8; 64];
+        for _ in 0..10 {
+            assert_eq!(fountain.read(&mut buffer).unwrap(), 64);
+            assert_eq!(buffer, [0u8; 64]);
+        }
+        assert_eq!(fountain.flowed(), 640);
+    }
+
+    #[test]
+    fn pattern() {
+        let mut fountain = Fountain::new(Spring::Pattern(b"abc".to_vec()));
+        let mut buffer = [0u8; 4];
+        fountain.read_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer, b"abca");
+        fountain.read_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer, b"bcab");
+    }
+
+    #[test]
+    fn random() {
+        let mut a = Fountain::new(Spring::Random(42));
+        let mut b = Fountain::new(Spring::Random(42));
+        let mut left = [0u8; 256];
+        let mut right = [0u8; 256];
+        a.read_exact(&mut left).unwrap();
+        b.read_exact(&mut right).unwrap();
+        assert_eq!(left, right);
+        assert!(left.iter().any(|&b| b != left[0]));
+    }
+
+    #[test]
+    fn split() {
+        let mut fountain = Fountain::new(Spring::Split);
+        let mut buffer = [0u8; 6];
+        for _ in 0..10 {
+            let n = fountain.read(&mut buffer).unwrap();
+            assert!(std::str::from_utf8(&buffer[..n]).is_err());
+        }
+        let mut text = String::new();
+        let fountain = Fountain::new(Spring::Split);
+        fountain.take(30).read_to_string(&mut text).unwrap();
+        assert_eq!(text, "€".repeat(10));
+    }
+
+    #[test]
+    fn broken() {
+        let mut text = String::new();
+        let err = Fountain::new(Spring::Broken)
+            .take(30)
+            .read_to_string(&mut text)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let mut buffer = [0u8; 3];
+        let n = Fountain::new(Spring::Broken).read(&mut buffer).unwrap();
+        assert_eq!(&buffer[..n], &EURO[..2]);
+    }
+
+    #[test]
+    fn budget() {
+        let mut fountain = Fountain::with_budget(Spring::Zeros, Budget::Panic(10));
+        let mut buffer = [0u8; 8];
+        assert_eq!(fountain.read(&mut buffer).unwrap(), 8);
+        assert_eq!(fountain.read(&mut buffer).unwrap(), 2);
+        assert_eq!(fountain.read(&mut []).unwrap(), 0);
+        let result = catch_unwind(move || fountain.read(&mut buffer));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn limited() {
+        // Callers who enforce their own limit never meet the budget
+        let fountain = Fountain::with_budget(Spring::Random(7), Budget::Abort(1000));
+        let mut everything = Vec::new();
+        fountain.take(999).read_to_end(&mut everything).unwrap();
+        assert_eq!(everything.len(), 999);
+    }
+}
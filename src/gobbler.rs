@@ -0,0 +1,78 @@
+/// `Gobbler` implements [Extend] and [std::iter::FromIterator] by calling `next()` twice for
+/// every item it keeps, throwing away the first answer. It doesn't care whether that first
+/// answer was `None`, so it will happily ask an iterator which isn't fused for more.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Gobbler;
+/// let gobbler: Gobbler<u8> = (1..=7).collect();
+/// assert_eq!(gobbler.inner(), &[2, 4, 6]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Gobbler<T> {
+    kept: Vec<T>,
+}
+
+impl<T> Gobbler<T> {
+    /// Constructs a new, empty `Gobbler`
+    pub fn new() -> Gobbler<T> {
+        Gobbler { kept: Vec::new() }
+    }
+
+    /// The items this `Gobbler` kept
+    pub fn inner(&self) -> &[T] {
+        &self.kept
+    }
+}
+
+impl<T> Default for Gobbler<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for Gobbler<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        loop {
+            let _ = iter.next();
+            match iter.next() {
+                Some(item) => self.kept.push(item),
+                None => break,
+            }
+        }
+    }
+}
+
+impl<T> std::iter::FromIterator<T> for Gobbler<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut gobbler = Gobbler::new();
+        gobbler.extend(iter);
+        gobbler
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let g: Gobbler<u8> = Gobbler::new();
+        assert!(g.inner().is_empty());
+    }
+
+    #[test]
+    fn even() {
+        let g: Gobbler<u8> = (1..=6).collect();
+        assert_eq!(g.inner(), &[2, 4, 6]);
+    }
+
+    #[test]
+    fn unfused() {
+        let mut answers = vec![None, Some(1), None, Some(2), None, None].into_iter();
+        let g: Gobbler<u8> = std::iter::from_fn(|| answers.next().flatten()).collect();
+        assert_eq!(g.inner(), &[1, 2]);
+    }
+}
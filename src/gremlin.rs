@@ -0,0 +1,150 @@
+use std::error::Error;
+use std::fmt;
+
+/// `Gremlin` implements [std::error::Error], but badly. Each Gremlin's [Error::source] is
+/// another Gremlin, one level less deep, down to depth zero, which has no source.
+///
+/// Its [fmt::Display] starts writing a message and then fails, which [ToString::to_string]
+/// and the [format!] family treat as a bug and panic. The [fmt::Debug] output is honest, so
+/// it is still possible to find out which Gremlin got into the works. Even a very deep chain
+/// is built, cloned, compared and dropped one Gremlin at a time, so it can't overflow the stack.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Gremlin;
+/// use std::error::Error;
+/// let gremlin = Gremlin::new(3);
+/// let mut chain = 0;
+/// let mut source = gremlin.source();
+/// while let Some(next) = source {
+///     chain += 1;
+///     source = next.source();
+/// }
+/// assert_eq!(chain, 3);
+/// ```
+///
+/// ```should_panic
+/// # use misfortunate::Gremlin;
+/// let message = Gremlin::new(0).to_string(); // Panics
+/// ```
+pub struct Gremlin {
+    depth: usize,
+    source: Option<Box<Gremlin>>,
+}
+
+impl Gremlin {
+    /// Constructs a new `Gremlin` with a chain of `depth` more Gremlins behind it
+    pub fn new(depth: usize) -> Gremlin {
+        let mut gremlin = Gremlin {
+            depth: 0,
+            source: None,
+        };
+        for depth in 1..=depth {
+            let source = Some(Box::new(gremlin));
+            gremlin = Gremlin { depth, source };
+        }
+        gremlin
+    }
+
+    /// How many more Gremlins are behind this one
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+// A chain of Gremlins is entirely decided by its depth, so these needn't walk the chain
+impl Clone for Gremlin {
+    fn clone(&self) -> Self {
+        Gremlin::new(self.depth)
+    }
+}
+
+impl PartialEq for Gremlin {
+    fn eq(&self, other: &Self) -> bool {
+        self.depth == other.depth
+    }
+}
+
+impl Eq for Gremlin {}
+
+impl fmt::Debug for Gremlin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gremlin")
+            .field("depth", &self.depth)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Gremlin {
+    fn drop(&mut self) {
+        let mut source = self.source.take();
+        while let Some(mut gremlin) = source {
+            source = gremlin.source.take();
+        }
+    }
+}
+
+impl fmt::Display for Gremlin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Gremlin at depth {} in the ", self.depth)?;
+        Err(fmt::Error)
+    }
+}
+
+impl Error for Gremlin {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|g| g as &(dyn Error + 'static))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write;
+    use std::io;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[test]
+    fn create() {
+        let gremlin = Gremlin::new(0);
+        assert_eq!(gremlin.depth(), 0);
+        assert!(gremlin.source().is_none());
+    }
+
+    #[test]
+    fn chain() {
+        let gremlin = Gremlin::new(2);
+        let source = gremlin.source().unwrap();
+        let gremlin = source.downcast_ref::<Gremlin>().unwrap();
+        assert_eq!(gremlin, &Gremlin::new(1));
+    }
+
+    #[test]
+    fn display_fails() {
+        let mut text = String::new();
+        assert!(write!(text, "{}", Gremlin::new(1)).is_err());
+        assert!(text.starts_with("Gremlin at depth 1"));
+        assert!(catch_unwind(|| Gremlin::new(0).to_string()).is_err());
+        let err = io::Error::other(Gremlin::new(0));
+        assert!(catch_unwind(AssertUnwindSafe(|| err.to_string())).is_err());
+        assert!(format!("{:?}", Gremlin::new(0)).contains("Gremlin"));
+    }
+
+    #[test]
+    fn deep() {
+        let gremlin = Gremlin::new(1_000_000);
+        let mut chain = 0;
+        let mut source = gremlin.source();
+        while let Some(next) = source {
+            chain += 1;
+            source = next.source();
+        }
+        assert_eq!(chain, 1_000_000);
+        let copy = gremlin.clone();
+        assert_eq!(copy, gremlin);
+        assert_eq!(format!("{copy:?}"), "Gremlin { depth: 1000000, .. }");
+        drop(copy);
+        drop(gremlin);
+    }
+}
@@ -0,0 +1,125 @@
+use std::io;
+use std::io::Read;
+
+/// `Groundhog` wraps any [std::io::Read] but only ever reads from it once. Every `read`
+/// gets the same first chunk again, so the stream never advances and never ends, like a
+/// device driver whose cursor is stuck.
+///
+/// Consumers which stop after a certain number of bytes, as with [Read::take], will finish
+/// having seen the same chunk over and over. Consumers which wait for the end of the stream,
+/// such as [Read::read_to_end], never will. If the very first read finds the `inner` reader
+/// already at its end, that empty chunk is repeated instead, so the Groundhog stays empty too.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Groundhog;
+/// use std::io::Read;
+/// let groundhog = Groundhog::new(&b"I got you babe. "[..]);
+/// let mut text = String::new();
+/// groundhog.take(40).read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "I got you babe. I got you babe. I got yo");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Groundhog<R> {
+    inner: R,
+    day: Option<Vec<u8>>,
+}
+
+impl<R: Read> Groundhog<R> {
+    /// Constructs a new `Groundhog` around the `inner` reader
+    pub fn new(inner: R) -> Groundhog<R> {
+        Groundhog { inner, day: None }
+    }
+
+    /// The chunk this `Groundhog` repeats, if it has read one yet
+    pub fn day(&self) -> Option<&[u8]> {
+        self.day.as_deref()
+    }
+}
+
+impl<R: Read> Read for Groundhog<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let day = match &self.day {
+            Some(day) => day,
+            None if buf.is_empty() => return Ok(0),
+            None => {
+                let n = self.inner.read(buf)?.min(buf.len());
+                self.day.insert(buf[..n].to_vec())
+            }
+        };
+        let n = day.len().min(buf.len());
+        buf[..n].copy_from_slice(&day[..n]);
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Braggart, LoadLetter, Outcome, Saboteur};
+    use std::io::ErrorKind;
+
+    #[test]
+    fn create() {
+        let groundhog = Groundhog::new(&b"Hello"[..]);
+        assert_eq!(groundhog.day(), None);
+    }
+
+    #[test]
+    fn stuck() {
+        let mut groundhog = Groundhog::new(&b"Hello, World"[..]);
+        let mut buffer = [0u8; 5];
+        assert_eq!(groundhog.read(&mut buffer).unwrap(), 5);
+        assert_eq!(groundhog.day(), Some(&b"Hello"[..]));
+        let mut small = [0u8; 3];
+        assert_eq!(groundhog.read(&mut small).unwrap(), 3);
+        assert_eq!(&small, b"Hel");
+        let mut large = [0u8; 8];
+        assert_eq!(groundhog.read(&mut large).unwrap(), 5);
+        assert_eq!(&large[..5], b"Hello");
+    }
+
+    #[test]
+    fn never_ends() {
+        // Without a limit, a consumer can only notice by counting its steps
+        let mut groundhog = Groundhog::new(&b"Hello"[..]);
+        let mut buffer = [0u8; 16];
+        let budget = 1000;
+        let steps = (0..budget)
+            .take_while(|_| groundhog.read(&mut buffer).unwrap() > 0)
+            .count();
+        assert_eq!(steps, budget);
+    }
+
+    #[test]
+    fn empty() {
+        let mut groundhog = Groundhog::new(&b""[..]);
+        let mut text = String::new();
+        assert_eq!(groundhog.read_to_string(&mut text).unwrap(), 0);
+        assert_eq!(groundhog.day(), Some(&b""[..]));
+    }
+
+    #[test]
+    fn overclaim() {
+        // The Groundhog only repeats what really fitted in the buffer
+        let mut groundhog = Groundhog::new(Braggart::new(&b"Hello"[..], 3));
+        let mut buffer = [0u8; 4];
+        assert_eq!(groundhog.read(&mut buffer).unwrap(), 4);
+        assert_eq!(groundhog.day(), Some(&b"Hell"[..]));
+        assert_eq!(groundhog.read(&mut buffer).unwrap(), 4);
+    }
+
+    #[test]
+    fn errors() {
+        let mut groundhog = Groundhog::new(LoadLetter::new(ErrorKind::TimedOut));
+        let mut buffer = [0u8; 4];
+        assert!(groundhog.read(&mut buffer).is_err());
+        assert_eq!(groundhog.day(), None);
+        let script = [Outcome::Err(ErrorKind::Interrupted)];
+        let mut groundhog = Groundhog::new(Saboteur::new(&b"Hi"[..], script));
+        assert!(groundhog.read(&mut buffer).is_err());
+        assert_eq!(groundhog.read(&mut buffer).unwrap(), 2);
+        assert_eq!(groundhog.day(), Some(&b"Hi"[..]));
+    }
+}
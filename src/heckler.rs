@@ -0,0 +1,140 @@
+use std::io;
+use std::io::{Error, ErrorKind, Read, Write};
+
+/// `Heckler` wraps any [std::io::Read] or [std::io::Write] and passes along every operation
+/// faithfully, but only after first interrupting, with [ErrorKind::Interrupted], a fixed
+/// number of times. Then it interrupts that many times before the next operation, and so on.
+///
+/// Code that correctly retries on `Interrupted`, such as [Read::read_exact] and
+/// [Write::write_all] will work fine, just slowly.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Heckler;
+/// use std::io::{ErrorKind, Read};
+/// let mut heckler = Heckler::new(&b"Hello"[..], 2);
+/// let mut buffer = [0u8; 5];
+/// let err = heckler.read(&mut buffer).err().unwrap();
+/// assert_eq!(err.kind(), ErrorKind::Interrupted);
+/// let err = heckler.read(&mut buffer).err().unwrap();
+/// assert_eq!(err.kind(), ErrorKind::Interrupted);
+/// assert_eq!(heckler.read(&mut buffer).unwrap(), 5);
+/// ```
+///
+/// ```
+/// # use misfortunate::Heckler;
+/// use std::io::Write;
+/// let mut heckler = Heckler::new(Vec::new(), 1000);
+/// heckler.write_all(b"Hello, World").unwrap();
+/// assert_eq!(heckler.inner(), b"Hello, World");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Heckler<T> {
+    inner: T,
+    interruptions: usize,
+    pending: usize,
+}
+
+impl<T> Heckler<T> {
+    /// Constructs a new `Heckler` which interrupts `interruptions` times before each
+    /// operation on `inner`
+    pub fn new(inner: T, interruptions: usize) -> Heckler<T> {
+        Heckler {
+            inner,
+            interruptions,
+            pending: interruptions,
+        }
+    }
+
+    /// The `inner` reader or writer
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    fn heckle(&mut self) -> io::Result<()> {
+        if self.pending == 0 {
+            self.pending = self.interruptions;
+            Ok(())
+        } else {
+            self.pending -= 1;
+            Err(Error::from(ErrorKind::Interrupted))
+        }
+    }
+}
+
+impl<T: Read> Read for Heckler<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.heckle()?;
+        self.inner.read(buf)
+    }
+}
+
+impl<T: Write> Write for Heckler<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.heckle()?;
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.heckle()?;
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Trickle;
+
+    #[test]
+    fn create() {
+        let h = Heckler::new(42u8, 5);
+        assert_eq!(*h.inner(), 42);
+    }
+
+    #[test]
+    fn polite() {
+        let mut h = Heckler::new(&b"abc"[..], 0);
+        let mut buffer = [0u8; 2];
+        assert_eq!(h.read(&mut buffer).unwrap(), 2);
+        assert_eq!(h.read(&mut buffer).unwrap(), 1);
+        assert_eq!(h.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn every_time() {
+        let mut h = Heckler::new(Vec::new(), 3);
+        for _ in 0..2 {
+            for _ in 0..3 {
+                let err = h.write(b"abc").err().unwrap();
+                assert_eq!(err.kind(), ErrorKind::Interrupted);
+            }
+            assert_eq!(h.write(b"abc").unwrap(), 3);
+        }
+        for _ in 0..3 {
+            let err = h.flush().err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::Interrupted);
+        }
+        assert!(h.flush().is_ok());
+        assert_eq!(h.inner(), b"abcabc");
+    }
+
+    #[test]
+    fn storm() {
+        let source = Trickle::new(b"Hello, World".to_vec());
+        let mut h = Heckler::new(source, 5000);
+        let mut text = String::new();
+        h.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "Hello, World");
+    }
+
+    #[test]
+    fn copy() {
+        let mut source = Heckler::new(&b"Hello, World"[..], 100);
+        let mut sink = Heckler::new(Vec::new(), 100);
+        let n = io::copy(&mut source, &mut sink).unwrap();
+        assert_eq!(n, 12);
+        assert_eq!(sink.inner(), b"Hello, World");
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+use std::io;
+use std::io::{Read, Write};
+
+/// `Hypocrite` wraps any [std::io::Read] or [std::io::Write] and its `read` and `write` pass
+/// along faithfully, but it also overrides the provided convenience methods, which do
+/// something else entirely.
+///
+/// * `read_exact` succeeds without reading anything
+/// * `read_to_end` and `read_to_string` read everything but only keep the first half,
+///   while reporting the full amount
+/// * `write_all` makes a single call to `write` and succeeds, however little was written
+/// * `write_fmt` succeeds without writing anything
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Hypocrite;
+/// use std::io::Read;
+/// let mut hypocrite = Hypocrite::new(&b"Hello, World"[..]);
+/// let mut buffer = [0u8; 5];
+/// hypocrite.read_exact(&mut buffer).unwrap();
+/// assert_eq!(buffer, [0u8; 5]);
+/// let mut text = String::new();
+/// assert_eq!(hypocrite.read_to_string(&mut text).unwrap(), 12);
+/// assert_eq!(text, "Hello,");
+/// ```
+///
+/// ```
+/// # use misfortunate::{Hypocrite, Trickle};
+/// use std::io::Write;
+/// let mut hypocrite = Hypocrite::new(Trickle::new(Vec::new()));
+/// hypocrite.write_all(b"Hello").unwrap();
+/// write!(hypocrite, "{}, {}", 1, 2).unwrap();
+/// assert_eq!(hypocrite.inner().written(), b"H");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hypocrite<T> {
+    inner: T,
+}
+
+impl<T> Hypocrite<T> {
+    /// Constructs a new `Hypocrite` around `inner`
+    pub fn new(inner: T) -> Hypocrite<T> {
+        Hypocrite { inner }
+    }
+
+    /// The `inner` reader or writer
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: Read> Read for Hypocrite<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+
+    fn read_exact(&mut self, _: &mut [u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let mut everything = Vec::new();
+        let n = self.inner.read_to_end(&mut everything)?;
+        buf.extend_from_slice(&everything[..n / 2]);
+        Ok(n)
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        let mut everything = String::new();
+        let n = self.inner.read_to_string(&mut everything)?;
+        let mut half = n / 2;
+        while !everything.is_char_boundary(half) {
+            half -= 1;
+        }
+        buf.push_str(&everything[..half]);
+        Ok(n)
+    }
+}
+
+impl<T: Write> Write for Hypocrite<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write(buf)?;
+        Ok(())
+    }
+
+    fn write_fmt(&mut self, _: fmt::Arguments<'_>) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LoadLetter, Trickle};
+
+    #[test]
+    fn create() {
+        let h = Hypocrite::new(42u8);
+        assert_eq!(*h.inner(), 42);
+    }
+
+    #[test]
+    fn honest_read() {
+        let mut h = Hypocrite::new(&b"Hello"[..]);
+        let mut buffer = [0u8; 8];
+        assert_eq!(h.read(&mut buffer).unwrap(), 5);
+        assert_eq!(&buffer[..5], b"Hello");
+    }
+
+    #[test]
+    fn read_exact() {
+        let mut h = Hypocrite::new(&b""[..]);
+        let mut buffer = [42u8; 8];
+        assert!(h.read_exact(&mut buffer).is_ok());
+        assert_eq!(buffer, [42u8; 8]);
+    }
+
+    #[test]
+    fn read_to_end() {
+        let mut h = Hypocrite::new(&b"abcdefg"[..]);
+        let mut buffer = b"X".to_vec();
+        assert_eq!(h.read_to_end(&mut buffer).unwrap(), 7);
+        assert_eq!(buffer, b"Xabc");
+    }
+
+    #[test]
+    fn read_to_string() {
+        let mut h = Hypocrite::new("❤❤❤".as_bytes());
+        let mut text = String::new();
+        assert_eq!(h.read_to_string(&mut text).unwrap(), 9);
+        assert_eq!(text, "❤");
+    }
+
+    #[test]
+    fn write_all() {
+        let mut h = Hypocrite::new(Trickle::new_limit(Vec::new(), 3));
+        assert!(h.write_all(b"Hello").is_ok());
+        assert_eq!(h.inner().written(), b"Hel");
+    }
+
+    #[test]
+    fn write_fmt() {
+        let mut h = Hypocrite::new(Vec::new());
+        assert!(writeln!(h, "Hello, World").is_ok());
+        assert!(h.inner().is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let mut h = Hypocrite::new(LoadLetter::default());
+        assert!(h.write_all(b"Hello").is_err());
+        assert!(h.flush().is_err());
+        let mut buffer = Vec::new();
+        assert!(h.read_to_end(&mut buffer).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use std::cell::Cell;
+
+/// The way a [Kaleidoscope] changes between one iteration and the next
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Twist {
+    /// Each iteration starts one item further along, wrapping around to the beginning
+    Rotate,
+    /// Iterations alternate between forwards and backwards
+    Reverse,
+    /// Each iteration stops one item sooner than the one before
+    Truncate,
+}
+
+/// `Kaleidoscope` holds a collection of items and implements [IntoIterator] for a reference to
+/// itself, so you can iterate over `&Kaleidoscope` just like `&Vec`. However each time you do
+/// it is turned a little, and the sequence you get is different from the previous one.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Kaleidoscope, Twist};
+/// let k = Kaleidoscope::new(vec![1, 2, 3], Twist::Rotate);
+/// let first: Vec<&u8> = k.into_iter().collect();
+/// let second: Vec<&u8> = k.into_iter().collect();
+/// assert_eq!(first, [&1, &2, &3]);
+/// assert_eq!(second, [&2, &3, &1]);
+/// ```
+///
+/// ```
+/// # use misfortunate::{Kaleidoscope, Twist};
+/// let k = Kaleidoscope::new(vec!['a', 'b', 'c'], Twist::Truncate);
+/// let count = (&k).into_iter().count();
+/// let mut copied = Vec::with_capacity(count);
+/// for c in &k {
+///     copied.push(*c);
+/// }
+/// assert_eq!(count, 3);
+/// assert_eq!(copied, ['a', 'b']);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Kaleidoscope<T> {
+    items: Vec<T>,
+    twist: Twist,
+    turns: Cell<usize>,
+}
+
+impl<T> Kaleidoscope<T> {
+    /// Constructs a new `Kaleidoscope` holding `items`, which will `twist` each time it is
+    /// iterated
+    pub fn new(items: Vec<T>, twist: Twist) -> Kaleidoscope<T> {
+        Kaleidoscope {
+            items,
+            twist,
+            turns: Cell::new(0),
+        }
+    }
+
+    /// The items in their original order
+    pub fn inner(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Kaleidoscope<T> {
+    type Item = &'a T;
+    type IntoIter = std::vec::IntoIter<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let turns = self.turns.replace(self.turns.get().wrapping_add(1));
+        let mut view: Vec<&'a T> = self.items.iter().collect();
+        match self.twist {
+            Twist::Rotate => {
+                if !view.is_empty() {
+                    let len = view.len();
+                    view.rotate_left(turns % len);
+                }
+            }
+            Twist::Reverse => {
+                if turns % 2 == 1 {
+                    view.reverse();
+                }
+            }
+            Twist::Truncate => view.truncate(view.len().saturating_sub(turns)),
+        }
+        view.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn<T: Copy>(k: &Kaleidoscope<T>) -> Vec<T> {
+        k.into_iter().copied().collect()
+    }
+
+    #[test]
+    fn create() {
+        let k = Kaleidoscope::new(vec![1u8, 2, 3], Twist::Rotate);
+        assert_eq!(k.inner(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn rotate() {
+        let k = Kaleidoscope::new(vec![1u8, 2, 3], Twist::Rotate);
+        assert_eq!(turn(&k), [1, 2, 3]);
+        assert_eq!(turn(&k), [2, 3, 1]);
+        assert_eq!(turn(&k), [3, 1, 2]);
+        assert_eq!(turn(&k), [1, 2, 3]);
+    }
+
+    #[test]
+    fn reverse() {
+        let k = Kaleidoscope::new(vec![1u8, 2, 3], Twist::Reverse);
+        assert_eq!(turn(&k), [1, 2, 3]);
+        assert_eq!(turn(&k), [3, 2, 1]);
+        assert_eq!(turn(&k), [1, 2, 3]);
+    }
+
+    #[test]
+    fn truncate() {
+        let k = Kaleidoscope::new(vec![1u8, 2, 3], Twist::Truncate);
+        assert_eq!(turn(&k), [1, 2, 3]);
+        assert_eq!(turn(&k), [1, 2]);
+        assert_eq!(turn(&k), [1]);
+        assert_eq!(turn(&k), []);
+        assert_eq!(turn(&k), []);
+    }
+
+    #[test]
+    fn empty() {
+        let k: Kaleidoscope<u8> = Kaleidoscope::new(Vec::new(), Twist::Rotate);
+        assert_eq!(turn(&k), []);
+        assert_eq!(turn(&k), []);
+    }
+}
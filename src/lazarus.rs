@@ -0,0 +1,79 @@
+use std::io;
+use std::io::Read;
+
+/// `Lazarus` wraps any [std::io::Read] but on every other call to `read`, starting with the
+/// first, it claims to have reached the end of the stream by reading nothing. Then it comes
+/// back to life, and the next read gets data from the `inner` reader as usual.
+///
+/// Code which believes the first end of stream is final will miss everything.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Lazarus;
+/// use std::io::Read;
+/// let mut lazarus = Lazarus::new(&b"Hello"[..]);
+/// let mut text = String::new();
+/// assert_eq!(lazarus.read_to_string(&mut text).unwrap(), 0);
+/// assert_eq!(lazarus.read_to_string(&mut text).unwrap(), 5);
+/// assert_eq!(text, "Hello");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Lazarus<R> {
+    inner: R,
+    dead: bool,
+}
+
+impl<R: Read> Lazarus<R> {
+    /// Constructs a new `Lazarus` around the `inner` reader
+    pub fn new(inner: R) -> Lazarus<R> {
+        Lazarus { inner, dead: false }
+    }
+}
+
+impl<R: Read> Read for Lazarus<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.dead = !self.dead;
+        if self.dead {
+            Ok(0)
+        } else {
+            self.inner.read(buf)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let _ = Lazarus::new(&b"Hello"[..]);
+    }
+
+    #[test]
+    fn reading() {
+        let mut lazarus = Lazarus::new(&b"abcd"[..]);
+        let mut buffer = [0u8; 2];
+        assert_eq!(lazarus.read(&mut buffer).unwrap(), 0);
+        assert_eq!(lazarus.read(&mut buffer).unwrap(), 2);
+        assert_eq!(&buffer, b"ab");
+        assert_eq!(lazarus.read(&mut buffer).unwrap(), 0);
+        assert_eq!(lazarus.read(&mut buffer).unwrap(), 2);
+        assert_eq!(&buffer, b"cd");
+        assert_eq!(lazarus.read(&mut buffer).unwrap(), 0);
+        assert_eq!(lazarus.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn resurrection() {
+        let mut lazarus = Lazarus::new(&b"Hello, World"[..]);
+        let mut collected = Vec::new();
+        let mut buffer = [0u8; 5];
+        for _ in 0..10 {
+            let n = lazarus.read(&mut buffer).unwrap();
+            collected.extend_from_slice(&buffer[..n]);
+        }
+        assert_eq!(collected, b"Hello, World");
+    }
+}
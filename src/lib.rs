@@ -5,6 +5,8 @@ pub(crate) trait Same {
     fn same(&self, other: &Self) -> bool;
 }
 
+mod prng;
+
 mod always;
 pub use crate::always::Always;
 mod blackhole;
@@ -26,7 +28,7 @@ pub use crate::reverse::Reverse;
 mod loaner;
 pub use crate::loaner::Loaner;
 mod comte;
-pub use crate::comte::Comte;
+pub use crate::comte::{Comte, Wand};
 mod multiplicity;
 pub use crate::multiplicity::Multiplicity;
 mod double;
@@ -43,3 +45,59 @@ mod exclusive;
 pub use crate::exclusive::Exclusive;
 mod lapse;
 pub use crate::lapse::Lapse;
+mod tripwire;
+pub use crate::tripwire::Tripwire;
+mod sieve;
+pub use crate::sieve::Sieve;
+mod satiated;
+pub use crate::satiated::Satiated;
+mod gobbler;
+pub use crate::gobbler::Gobbler;
+mod tantrum;
+pub use crate::tantrum::Tantrum;
+mod kaleidoscope;
+pub use crate::kaleidoscope::{Kaleidoscope, Twist};
+mod changeling;
+pub use crate::changeling::{Changeling, Divergence};
+mod puppet;
+pub use crate::puppet::{Outcome, Puppet};
+mod failafter;
+pub use crate::failafter::FailAfter;
+mod trickle;
+pub use crate::trickle::Trickle;
+mod heckler;
+pub use crate::heckler::Heckler;
+mod stonewall;
+pub use crate::stonewall::Stonewall;
+mod lazarus;
+pub use crate::lazarus::Lazarus;
+mod braggart;
+pub use crate::braggart::Braggart;
+mod busybody;
+pub use crate::busybody::Busybody;
+mod vertigo;
+pub use crate::vertigo::{Spin, Vertigo};
+mod quicksand;
+pub use crate::quicksand::{Quicksand, Slip};
+mod scatterbrain;
+pub use crate::scatterbrain::{Muddle, Scatterbrain};
+mod hypocrite;
+pub use crate::hypocrite::Hypocrite;
+mod saboteur;
+pub use crate::saboteur::Saboteur;
+mod plan;
+pub use crate::plan::{Plan, PlanError, Steps};
+mod transcript;
+pub use crate::transcript::{Call, Parrot, Stenographer, Transcript, TranscriptError};
+mod witness;
+pub use crate::witness::{Affidavit, Testimony, Witness};
+mod telephone;
+pub use crate::telephone::{Earpiece, Mishap, Mouthpiece, Noise, Telephone};
+mod groundhog;
+pub use crate::groundhog::Groundhog;
+mod dormouse;
+pub use crate::dormouse::{Alarm, Dormouse};
+mod fountain;
+pub use crate::fountain::{Budget, Fountain, Spring};
+mod gremlin;
+pub use crate::gremlin::Gremlin;
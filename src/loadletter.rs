@@ -1,6 +1,29 @@
+use std::error;
 use std::io;
 use std::io::{Error, ErrorKind, Read, Write};
 
+/// A custom error which a [LoadLetter] can hand out again and again
+trait Payload: error::Error + Send + Sync {
+    fn boxed(&self) -> Box<dyn Payload>;
+    fn into_error(self: Box<Self>) -> Box<dyn error::Error + Send + Sync>;
+}
+
+impl<E: error::Error + Clone + Send + Sync + 'static> Payload for E {
+    fn boxed(&self) -> Box<dyn Payload> {
+        Box::new(self.clone())
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn error::Error + Send + Sync> {
+        self
+    }
+}
+
+impl Clone for Box<dyn Payload> {
+    fn clone(&self) -> Self {
+        self.boxed()
+    }
+}
+
 /// `LoadLetter` implements [std::io::Read] and [std::io::Write] by just always reporting an
 /// error.  LoadLetter violates the social contracts of [Read] and [Write], and as a result your
 /// program may have undesirable behaviour if you try to use a LoadLetter.
@@ -31,6 +54,7 @@ use std::io::{Error, ErrorKind, Read, Write};
 pub struct LoadLetter<'a> {
     kind: ErrorKind,
     error: &'a str,
+    payload: Option<Box<dyn Payload>>,
 }
 
 impl<'a> LoadLetter<'a> {
@@ -47,8 +71,7 @@ impl<'a> LoadLetter<'a> {
     /// assert_eq!(err.kind(), ErrorKind::AlreadyExists);
     /// ```
     pub fn new(kind: ErrorKind) -> Self {
-        let error = "";
-        Self { kind, error }
+        Self::new_msg(kind, "")
     }
 
     /// Constructs a new `LoadLetter` with an `ErrorKind` and a specified error text string
@@ -65,7 +88,42 @@ impl<'a> LoadLetter<'a> {
     /// assert_eq!(err.to_string(), "I don't want to");
     /// ```
     pub fn new_msg(kind: ErrorKind, error: &'a str) -> Self {
-        Self { kind, error }
+        let payload = None;
+        Self {
+            kind,
+            error,
+            payload,
+        }
+    }
+
+    /// Constructs a new `LoadLetter` with an `ErrorKind` and a custom `error`, a copy of which
+    /// is wrapped inside every `Error` it returns, where [Error::get_ref], [Error::into_inner]
+    /// and [Error::downcast] can find it, along with any [std::error::Error::source] chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use misfortunate::{Gremlin, LoadLetter};
+    /// use std::error::Error;
+    /// use std::io::{ErrorKind, Write};
+    /// let mut ll = LoadLetter::new_error(ErrorKind::Interrupted, Gremlin::new(2));
+    /// let err = ll.write(b"Hello").err().unwrap();
+    /// assert_eq!(err.kind(), ErrorKind::Interrupted);
+    /// let gremlin = err.get_ref().unwrap().downcast_ref::<Gremlin>().unwrap();
+    /// assert_eq!(gremlin.depth(), 2);
+    /// let gremlin = gremlin.source().unwrap().downcast_ref::<Gremlin>().unwrap();
+    /// assert_eq!(gremlin.depth(), 1);
+    /// ```
+    pub fn new_error<E>(kind: ErrorKind, error: E) -> Self
+    where
+        E: error::Error + Clone + Send + Sync + 'static,
+    {
+        let payload: Option<Box<dyn Payload>> = Some(Box::new(error));
+        Self {
+            kind,
+            error: "",
+            payload,
+        }
     }
 
     /// Provides an example of the `Error` this type will return for all operations
@@ -80,23 +138,26 @@ impl<'a> LoadLetter<'a> {
     /// assert_eq!(err.kind(), ErrorKind::Unsupported);
     /// assert_eq!(err.to_string(), "I don't want to");
     pub fn error(&self) -> Error {
-        Error::new(self.kind, self.error)
+        match &self.payload {
+            Some(payload) => Error::new(self.kind, payload.boxed().into_error()),
+            None => Error::new(self.kind, self.error),
+        }
     }
 }
 
 impl Read for LoadLetter<'_> {
     fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
-        Err(Error::new(self.kind, self.error))
+        Err(self.error())
     }
 }
 
 impl Write for LoadLetter<'_> {
     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
-        Err(Error::new(self.kind, self.error))
+        Err(self.error())
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        Err(Error::new(self.kind, self.error))
+        Err(self.error())
     }
 }
 
@@ -105,13 +166,14 @@ impl Default for LoadLetter<'_> {
     fn default() -> Self {
         let kind = ErrorKind::Other;
         let error = "PC Load Letter";
-        LoadLetter { kind, error }
+        LoadLetter::new_msg(kind, error)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Gremlin;
 
     #[test]
     fn create() {
@@ -131,6 +193,18 @@ mod tests {
         assert_eq!("PC Load Letter", inner.to_string());
     }
 
+    #[test]
+    fn custom() {
+        let ll = LoadLetter::new_error(ErrorKind::TimedOut, Gremlin::new(0));
+        let copy = ll.clone();
+        for ll in [ll, copy] {
+            let err = ll.error();
+            assert_eq!(ErrorKind::TimedOut, err.kind());
+            let gremlin = err.downcast::<Gremlin>().unwrap();
+            assert_eq!(gremlin.depth(), 0);
+        }
+    }
+
     #[test]
     fn reading() {
         let mut ll: LoadLetter<'_> = Default::default();
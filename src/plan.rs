@@ -0,0 +1,396 @@
+use crate::Outcome;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::ErrorKind;
+use std::io::ErrorKind::*;
+use std::str::FromStr;
+
+const KINDS: [ErrorKind; 39] = [
+    NotFound,
+    PermissionDenied,
+    ConnectionRefused,
+    ConnectionReset,
+    HostUnreachable,
+    NetworkUnreachable,
+    ConnectionAborted,
+    NotConnected,
+    AddrInUse,
+    AddrNotAvailable,
+    NetworkDown,
+    BrokenPipe,
+    AlreadyExists,
+    WouldBlock,
+    NotADirectory,
+    IsADirectory,
+    DirectoryNotEmpty,
+    ReadOnlyFilesystem,
+    StaleNetworkFileHandle,
+    InvalidInput,
+    InvalidData,
+    TimedOut,
+    WriteZero,
+    StorageFull,
+    NotSeekable,
+    QuotaExceeded,
+    FileTooLarge,
+    ResourceBusy,
+    ExecutableFileBusy,
+    Deadlock,
+    CrossesDevices,
+    TooManyLinks,
+    InvalidFilename,
+    ArgumentListTooLong,
+    Interrupted,
+    Unsupported,
+    UnexpectedEof,
+    OutOfMemory,
+    Other,
+];
+
+/// Finds the [ErrorKind] with this name, as spelled in Rust, e.g. "ConnectionReset"
+pub(crate) fn kind_from_name(name: &str) -> Option<ErrorKind> {
+    KINDS
+        .iter()
+        .copied()
+        .find(|kind| format!("{kind:?}") == name)
+}
+
+/// `PlanError` explains which part of a [Plan] or [Outcome] could not be understood
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanError {
+    step: String,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unrecognised plan step `{}`", self.step)
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+/// An [Outcome] can be written as text: `ok` lets as many bytes through as possible, `eof`
+/// is `Ok(0)`, `short:N` is any other `Ok(N)` and `err:Kind` is an error of that
+/// [ErrorKind], spelled as in Rust, for example `err:ConnectionReset`
+impl FromStr for Outcome {
+    type Err = PlanError;
+
+    fn from_str(s: &str) -> Result<Self, PlanError> {
+        let error = || PlanError {
+            step: s.trim().to_owned(),
+        };
+        match s.trim() {
+            "ok" => Ok(Outcome::Ok(usize::MAX)),
+            "eof" => Ok(Outcome::Ok(0)),
+            step => match step.split_once(':') {
+                Some(("short", n)) => n.trim().parse().map(Outcome::Ok).map_err(|_| error()),
+                Some(("err", name)) => kind_from_name(name.trim())
+                    .map(Outcome::Err)
+                    .ok_or_else(error),
+                _ => Err(error()),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Ok(usize::MAX) => f.write_str("ok"),
+            Outcome::Ok(0) => f.write_str("eof"),
+            Outcome::Ok(n) => write!(f, "short:{n}"),
+            Outcome::Err(kind) => write!(f, "err:{kind:?}"),
+        }
+    }
+}
+
+/// `Plan` is a sequence of [Outcome]s which can be written down as text, so that it can live
+/// in a config file or an environment variable and drive a [crate::Puppet] or
+/// [crate::Saboteur]. Steps are separated by commas, and any step may be followed by `*N` to
+/// repeat it N times. Repeated steps are stored just once, with their count, and are still
+/// counted down rather than written out in full when the Plan is followed, so even a huge
+/// count costs nothing.
+///
+/// A Plan implements [FromStr] to parse this text, and [fmt::Display] to write it back out
+/// again in the same form, so that a failing plan can be logged and replayed.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Outcome, Plan, Saboteur};
+/// use std::io::{ErrorKind, Read};
+/// let plan: Plan = "ok*2, short:3, err:ConnectionReset, eof".parse().unwrap();
+/// assert_eq!(plan.len(), 5);
+/// assert_eq!(plan.to_string(), "ok*2, short:3, err:ConnectionReset, eof");
+///
+/// let mut saboteur = Saboteur::new(&b"Hello, World"[..], plan);
+/// let mut buffer = [0u8; 4];
+/// assert_eq!(saboteur.read(&mut buffer).unwrap(), 4);
+/// assert_eq!(saboteur.read(&mut buffer).unwrap(), 4);
+/// assert_eq!(saboteur.read(&mut buffer).unwrap(), 3);
+/// let err = saboteur.read(&mut buffer).err().unwrap();
+/// assert_eq!(err.kind(), ErrorKind::ConnectionReset);
+/// assert_eq!(saboteur.read(&mut buffer).unwrap(), 0);
+/// assert_eq!(saboteur.read(&mut buffer).unwrap(), 1);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Plan {
+    runs: Vec<(Outcome, usize)>,
+}
+
+impl Plan {
+    /// The [Outcome]s of this `Plan`, in order
+    pub fn steps(&self) -> impl Iterator<Item = Outcome> + '_ {
+        self.runs
+            .iter()
+            .flat_map(|&(outcome, count)| std::iter::repeat_n(outcome, count))
+    }
+
+    /// How many steps this `Plan` has, or `usize::MAX` if there are even more
+    pub fn len(&self) -> usize {
+        self.runs
+            .iter()
+            .fold(0, |len: usize, &(_, count)| len.saturating_add(count))
+    }
+
+    /// Whether this `Plan` has no steps at all
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    fn push(&mut self, outcome: Outcome, count: usize) {
+        match self.runs.last_mut() {
+            _ if count == 0 => {}
+            Some((last, n)) if *last == outcome => *n = n.saturating_add(count),
+            _ => self.runs.push((outcome, count)),
+        }
+    }
+}
+
+impl FromStr for Plan {
+    type Err = PlanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut plan = Plan::default();
+        if s.trim().is_empty() {
+            return Ok(plan);
+        }
+        for item in s.split(',') {
+            let (step, count) = match item.split_once('*') {
+                Some((step, count)) => {
+                    let count = count.trim().parse().map_err(|_| PlanError {
+                        step: item.trim().to_owned(),
+                    })?;
+                    (step, count)
+                }
+                None => (item, 1),
+            };
+            let outcome: Outcome = step.parse()?;
+            plan.push(outcome, count);
+        }
+        Ok(plan)
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, &(outcome, count)) in self.runs.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if count == 1 {
+                write!(f, "{outcome}")?;
+            } else {
+                write!(f, "{outcome}*{count}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl IntoIterator for Plan {
+    type Item = Outcome;
+    type IntoIter = Steps;
+
+    fn into_iter(self) -> Steps {
+        Steps {
+            runs: self.runs.into(),
+        }
+    }
+}
+
+impl<const N: usize> From<[Outcome; N]> for Plan {
+    fn from(steps: [Outcome; N]) -> Self {
+        steps.into_iter().collect()
+    }
+}
+
+impl From<Vec<Outcome>> for Plan {
+    fn from(steps: Vec<Outcome>) -> Self {
+        steps.into_iter().collect()
+    }
+}
+
+/// `Steps` is an [Iterator] over the [Outcome]s of a [Plan], which counts down each repeated
+/// step rather than writing it out in full
+#[derive(Clone, Debug, Default)]
+pub struct Steps {
+    runs: VecDeque<(Outcome, usize)>,
+}
+
+impl Iterator for Steps {
+    type Item = Outcome;
+
+    fn next(&mut self) -> Option<Outcome> {
+        let (outcome, count) = self.runs.front_mut()?;
+        let outcome = *outcome;
+        *count -= 1;
+        if *count == 0 {
+            self.runs.pop_front();
+        }
+        Some(outcome)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self
+            .runs
+            .iter()
+            .try_fold(0, |len: usize, &(_, count)| len.checked_add(count));
+        (len.unwrap_or(usize::MAX), len)
+    }
+}
+
+impl std::iter::FromIterator<Outcome> for Plan {
+    fn from_iter<I: IntoIterator<Item = Outcome>>(iter: I) -> Self {
+        let mut plan = Plan::default();
+        for outcome in iter {
+            plan.push(outcome, 1);
+        }
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds() {
+        for kind in KINDS {
+            assert_eq!(kind_from_name(&format!("{kind:?}")), Some(kind));
+        }
+        assert_eq!(kind_from_name("PC Load Letter"), None);
+    }
+
+    #[test]
+    fn outcomes() {
+        assert_eq!("ok".parse(), Ok(Outcome::Ok(usize::MAX)));
+        assert_eq!(" eof ".parse(), Ok(Outcome::Ok(0)));
+        assert_eq!("short:7".parse(), Ok(Outcome::Ok(7)));
+        assert_eq!("err:TimedOut".parse(), Ok(Outcome::Err(ErrorKind::TimedOut)));
+        assert!("short:many".parse::<Outcome>().is_err());
+        assert!("err:Kaiju".parse::<Outcome>().is_err());
+        assert!("sure".parse::<Outcome>().is_err());
+    }
+
+    #[test]
+    fn parsing() {
+        let plan: Plan = "ok, short:1*3,err:Interrupted * 2".parse().unwrap();
+        assert_eq!(
+            plan.steps().collect::<Vec<_>>(),
+            [
+                Outcome::Ok(usize::MAX),
+                Outcome::Ok(1),
+                Outcome::Ok(1),
+                Outcome::Ok(1),
+                Outcome::Err(ErrorKind::Interrupted),
+                Outcome::Err(ErrorKind::Interrupted),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let plan: Plan = "".parse().unwrap();
+        assert!(plan.is_empty());
+        assert_eq!(plan.steps().count(), 0);
+        assert_eq!(plan.to_string(), "");
+    }
+
+    #[test]
+    fn errors() {
+        let err = "ok, short:x, eof".parse::<Plan>().err().unwrap();
+        assert_eq!(err.to_string(), "unrecognised plan step `short:x`");
+        let err = "ok*lots".parse::<Plan>().err().unwrap();
+        assert_eq!(err.to_string(), "unrecognised plan step `ok*lots`");
+        assert!("ok,,eof".parse::<Plan>().is_err());
+        let err = "ok*99999999999999999999999".parse::<Plan>().err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "unrecognised plan step `ok*99999999999999999999999`"
+        );
+    }
+
+    #[test]
+    fn huge() {
+        let plan: Plan = "short:1*99999999999999, eof*0, short:1*2, eof".parse().unwrap();
+        assert_eq!(plan.len(), 100000000000002);
+        assert_eq!(plan.to_string(), "short:1*100000000000001, eof");
+        let mut steps = plan.into_iter();
+        assert_eq!(steps.next(), Some(Outcome::Ok(1)));
+        assert_eq!(steps.size_hint(), (100000000000001, Some(100000000000001)));
+        let plan: Plan = "ok*18446744073709551615, eof".parse().unwrap();
+        assert_eq!(plan.len(), usize::MAX);
+        assert_eq!(plan.into_iter().size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn followed() {
+        use crate::{Puppet, Saboteur};
+        use std::io::{Read, Write};
+
+        let plan: Plan = "short:1*100000000000, eof".parse().unwrap();
+        let mut saboteur = Saboteur::new(std::io::repeat(b'x'), plan.clone());
+        let mut buffer = [0u8; 4];
+        for _ in 0..10 {
+            assert_eq!(saboteur.read(&mut buffer).unwrap(), 1);
+        }
+        let mut puppet = Puppet::new(plan, Outcome::Ok(0));
+        assert_eq!(puppet.write(b"Hello").unwrap(), 1);
+    }
+
+    #[test]
+    fn equality() {
+        let long: Plan = "eof, eof, eof".parse().unwrap();
+        let short: Plan = "eof*3".parse().unwrap();
+        assert_eq!(long, short);
+    }
+
+    #[test]
+    fn round_trip() {
+        let plan: Plan = [
+            Outcome::Ok(5),
+            Outcome::Ok(5),
+            Outcome::Ok(0),
+            Outcome::Err(ErrorKind::WouldBlock),
+            Outcome::Ok(5),
+        ]
+        .into_iter()
+        .collect();
+        let text = plan.to_string();
+        assert_eq!(text, "short:5*2, eof, err:WouldBlock, short:5");
+        assert_eq!(text.parse(), Ok(plan));
+    }
+
+    #[test]
+    fn puppet() {
+        use crate::Puppet;
+        use std::io::Write;
+
+        let plan: Plan = "short:2, err:BrokenPipe".parse().unwrap();
+        let mut puppet = Puppet::new(plan, Outcome::Ok(usize::MAX));
+        assert_eq!(puppet.write(b"Hello").unwrap(), 2);
+        assert!(puppet.write(b"Hello").is_err());
+        assert_eq!(puppet.write(b"Hello").unwrap(), 5);
+    }
+}
@@ -0,0 +1,52 @@
+/// `Prng` is a tiny SplitMix64 pseudo-random number generator. Misfortunate types which
+/// misbehave at random use this so that the same seed always gives the same misbehaviour.
+#[derive(Clone, Debug)]
+pub(crate) struct Prng {
+    state: u64,
+}
+
+impl Prng {
+    pub(crate) fn new(seed: u64) -> Prng {
+        Prng { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number less than `n`, which must not be zero
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// True with a chance of one in `n`, or never if `n` is zero
+    pub(crate) fn one_in(&mut self, n: u32) -> bool {
+        n != 0 && self.next_u64().is_multiple_of(u64::from(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeatable() {
+        let mut a = Prng::new(42);
+        let mut b = Prng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn odds() {
+        let mut p = Prng::new(7);
+        assert!((0..100).all(|_| p.one_in(1)));
+        assert!((0..100).all(|_| !p.one_in(0)));
+        assert!((0..100).all(|_| p.below(3) < 3));
+    }
+}
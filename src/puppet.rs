@@ -0,0 +1,173 @@
+use crate::{Plan, Steps};
+use std::io;
+use std::io::{Error, ErrorKind, Read, Write};
+
+/// `Outcome` is the scripted result of a single I/O call
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// Success, moving no more than this many bytes. `Ok(0)` is thus the end of a stream
+    Ok(usize),
+    /// Failure, with an [Error] of this [ErrorKind]
+    Err(ErrorKind),
+}
+
+/// `Puppet` implements [std::io::Read] and [std::io::Write] by doing exactly what its script
+/// says. Every call to `read`, `write` or `flush` takes the next [Outcome] from the script and
+/// once the script runs out every call gets the `fallback` Outcome.
+///
+/// When an `Ok(n)` Outcome is larger than the buffer, only the buffer's length is reported.
+/// A Puppet has nothing interesting to say, so whatever it reads is zero bytes, and whatever
+/// is written to it is discarded.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Outcome, Puppet};
+/// use std::io::{ErrorKind, Write};
+/// let mut puppet = Puppet::new(
+///     [Outcome::Ok(8), Outcome::Ok(8), Outcome::Err(ErrorKind::ConnectionReset)],
+///     Outcome::Ok(usize::MAX),
+/// );
+/// let buffer = [42u8; 1024];
+/// assert_eq!(puppet.write(&buffer).unwrap(), 8);
+/// assert_eq!(puppet.write(&buffer).unwrap(), 8);
+/// let err = puppet.write(&buffer).err().unwrap();
+/// assert_eq!(err.kind(), ErrorKind::ConnectionReset);
+/// assert_eq!(puppet.write(&buffer).unwrap(), 1024);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Puppet {
+    script: Steps,
+    fallback: Outcome,
+}
+
+impl Puppet {
+    /// Constructs a new `Puppet` which follows the `script` and then the `fallback` forever.
+    /// The script is a [Plan], or an array or [Vec] of Outcomes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use misfortunate::{Outcome, Puppet};
+    /// use std::io::{ErrorKind, Read};
+    /// let mut puppet = Puppet::new([Outcome::Ok(3), Outcome::Ok(0)], Outcome::Ok(2));
+    /// let mut buffer = [42u8; 4];
+    /// assert_eq!(puppet.read(&mut buffer).unwrap(), 3);
+    /// assert_eq!(buffer, [0, 0, 0, 42]);
+    /// assert_eq!(puppet.read(&mut buffer).unwrap(), 0);
+    /// assert_eq!(puppet.read(&mut buffer).unwrap(), 2);
+    /// assert_eq!(puppet.read(&mut buffer).unwrap(), 2);
+    /// ```
+    pub fn new<P: Into<Plan>>(script: P, fallback: Outcome) -> Self {
+        let script = script.into().into_iter();
+        Self { script, fallback }
+    }
+
+    fn cue(&mut self) -> Outcome {
+        self.script.next().unwrap_or(self.fallback)
+    }
+}
+
+impl Read for Puppet {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.cue() {
+            Outcome::Ok(n) => {
+                let n = n.min(buf.len());
+                buf[..n].fill(0);
+                Ok(n)
+            }
+            Outcome::Err(kind) => Err(Error::from(kind)),
+        }
+    }
+}
+
+impl Write for Puppet {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.cue() {
+            Outcome::Ok(n) => Ok(n.min(buf.len())),
+            Outcome::Err(kind) => Err(Error::from(kind)),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.cue() {
+            Outcome::Ok(_) => Ok(()),
+            Outcome::Err(kind) => Err(Error::from(kind)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let _ = Puppet::new(Vec::new(), Outcome::Ok(0));
+    }
+
+    #[test]
+    fn reading() {
+        let mut puppet = Puppet::new(
+            [Outcome::Err(ErrorKind::Interrupted), Outcome::Ok(5)],
+            Outcome::Err(ErrorKind::TimedOut),
+        );
+        let mut buffer = [42u8; 10];
+        let err = puppet.read(&mut buffer).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+        assert_eq!(puppet.read(&mut buffer).unwrap(), 5);
+        let err = puppet.read(&mut buffer).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+        let err = puppet.read(&mut buffer).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn read_exact() {
+        let mut puppet = Puppet::new(
+            [
+                Outcome::Ok(2),
+                Outcome::Err(ErrorKind::Interrupted),
+                Outcome::Ok(2),
+            ],
+            Outcome::Ok(0),
+        );
+        let mut buffer = [42u8; 4];
+        assert!(puppet.read_exact(&mut buffer).is_ok());
+        let err = puppet.read_exact(&mut buffer).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn writing() {
+        let mut puppet = Puppet::new(
+            [Outcome::Ok(3), Outcome::Err(ErrorKind::BrokenPipe)],
+            Outcome::Ok(usize::MAX),
+        );
+        let buffer = [42u8; 10];
+        assert_eq!(puppet.write(&buffer).unwrap(), 3);
+        let err = puppet.write(&buffer).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+        assert_eq!(puppet.write(&buffer).unwrap(), 10);
+        assert!(puppet.flush().is_ok());
+    }
+
+    #[test]
+    fn flushing() {
+        let mut puppet = Puppet::new(
+            [Outcome::Ok(0), Outcome::Err(ErrorKind::StorageFull)],
+            Outcome::Ok(0),
+        );
+        assert!(puppet.flush().is_ok());
+        let err = puppet.flush().err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::StorageFull);
+        assert!(puppet.flush().is_ok());
+    }
+
+    #[test]
+    fn write_all() {
+        let mut puppet = Puppet::new([Outcome::Ok(1), Outcome::Ok(0)], Outcome::Ok(1));
+        let err = puppet.write_all(b"Hello").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+    }
+}
@@ -0,0 +1,174 @@
+use std::io;
+use std::io::{BufRead, Read};
+
+/// How the ground shifts beneath a [Quicksand]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Slip {
+    /// Calling `fill_buf` again without a `consume` in between loses a byte
+    Shifting,
+    /// `consume` ignores the amount it is given and throws away the entire buffer
+    Careless,
+    /// Every other call to `fill_buf`, starting with the first, returns an empty buffer
+    Hiccup,
+}
+
+const CAPACITY: usize = 16;
+
+/// `Quicksand` implements [std::io::BufRead] over any [std::io::Read], like
+/// [std::io::BufReader] but with a tiny buffer of only 16 bytes, and rather than `fill_buf`
+/// and `consume` working together as they should, they [Slip].
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Quicksand, Slip};
+/// use std::io::BufRead;
+/// let mut sand = Quicksand::new(&b"Hello, World"[..], Slip::Shifting);
+/// assert_eq!(sand.fill_buf().unwrap(), b"Hello, World");
+/// assert_eq!(sand.fill_buf().unwrap(), b"ello, World");
+/// sand.consume(5);
+/// assert_eq!(sand.fill_buf().unwrap(), b" World");
+/// ```
+///
+/// ```
+/// # use misfortunate::{Quicksand, Slip};
+/// use std::io::BufRead;
+/// let sand = Quicksand::new(&b"one\ntwo\nthree\nfour\n"[..], Slip::Careless);
+/// let lines: Vec<String> = sand.lines().map(|line| line.unwrap()).collect();
+/// assert_eq!(lines, ["one", "ur"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Quicksand<R> {
+    inner: R,
+    slip: Slip,
+    buffer: Vec<u8>,
+    pos: usize,
+    peeked: bool,
+    hiccup: bool,
+}
+
+impl<R: Read> Quicksand<R> {
+    /// Constructs a new `Quicksand` reading from `inner`, which will [Slip] in this way
+    pub fn new(inner: R, slip: Slip) -> Quicksand<R> {
+        Quicksand {
+            inner,
+            slip,
+            buffer: Vec::with_capacity(CAPACITY),
+            pos: 0,
+            peeked: false,
+            hiccup: false,
+        }
+    }
+}
+
+impl<R: Read> BufRead for Quicksand<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        match self.slip {
+            Slip::Shifting => {
+                if self.peeked {
+                    self.pos = (self.pos + 1).min(self.buffer.len());
+                }
+                self.peeked = true;
+            }
+            Slip::Hiccup => {
+                self.hiccup = !self.hiccup;
+                if self.hiccup {
+                    return Ok(&[]);
+                }
+            }
+            Slip::Careless => (),
+        }
+        if self.pos >= self.buffer.len() {
+            self.buffer.resize(CAPACITY, 0);
+            let n = self.inner.read(&mut self.buffer)?;
+            self.buffer.truncate(n.min(CAPACITY));
+            self.pos = 0;
+        }
+        Ok(&self.buffer[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = match self.slip {
+            Slip::Careless => self.buffer.len(),
+            _ => (self.pos + amt).min(self.buffer.len()),
+        };
+        self.peeked = false;
+    }
+}
+
+impl<R: Read> Read for Quicksand<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: &[u8] = b"The quick brown fox\njumps over\nthe lazy dog\n";
+
+    #[test]
+    fn create() {
+        let _ = Quicksand::new(LINES, Slip::Hiccup);
+    }
+
+    #[test]
+    fn shifting() {
+        let mut sand = Quicksand::new(LINES, Slip::Shifting);
+        assert_eq!(sand.fill_buf().unwrap(), b"The quick brown ");
+        assert_eq!(sand.fill_buf().unwrap(), b"he quick brown ");
+        assert_eq!(sand.fill_buf().unwrap(), b"e quick brown ");
+        sand.consume(14);
+        assert_eq!(sand.fill_buf().unwrap(), b"fox\njumps over\nt");
+    }
+
+    #[test]
+    fn shifting_lines() {
+        // read_line only looks once before each consume, so it isn't fooled
+        let sand = Quicksand::new(LINES, Slip::Shifting);
+        let lines: Vec<String> = sand.lines().map(|line| line.unwrap()).collect();
+        assert_eq!(lines, ["The quick brown fox", "jumps over", "the lazy dog"]);
+    }
+
+    #[test]
+    fn careless() {
+        let mut sand = Quicksand::new(LINES, Slip::Careless);
+        let mut line = String::new();
+        sand.read_line(&mut line).unwrap();
+        assert_eq!(line, "The quick brown fox\n");
+        line.clear();
+        sand.read_line(&mut line).unwrap();
+        assert_eq!(line, "he lazy dog\n");
+    }
+
+    #[test]
+    fn hiccup() {
+        let mut sand = Quicksand::new(LINES, Slip::Hiccup);
+        let mut line = String::new();
+        assert_eq!(sand.read_line(&mut line).unwrap(), 0);
+        assert_eq!(sand.read_line(&mut line).unwrap(), 16);
+        assert_eq!(line, "The quick brown ");
+    }
+
+    #[test]
+    fn hiccup_split() {
+        let sand = Quicksand::new(&b"a,b,c"[..], Slip::Hiccup);
+        let parts: Vec<Vec<u8>> = sand.split(b',').map(|part| part.unwrap()).collect();
+        assert!(parts.is_empty());
+    }
+
+    #[test]
+    fn reading() {
+        let mut sand = Quicksand::new(LINES, Slip::Careless);
+        let mut buffer = [0u8; 4];
+        assert_eq!(sand.read(&mut buffer).unwrap(), 4);
+        assert_eq!(&buffer, b"The ");
+        assert_eq!(sand.read(&mut buffer).unwrap(), 4);
+        assert_eq!(&buffer, b"fox\n");
+    }
+}
@@ -0,0 +1,222 @@
+use crate::prng::Prng;
+use crate::{Outcome, Plan, Steps};
+use std::io;
+use std::io::{Error, Read, Seek, SeekFrom, Write};
+
+#[derive(Clone, Debug)]
+enum Schedule {
+    Script(Steps),
+    Random {
+        prng: Prng,
+        one_in: u32,
+        faults: Vec<Outcome>,
+    },
+}
+
+/// `Saboteur` wraps any [std::io::Read], [std::io::Write] or [std::io::Seek], such as a
+/// [std::fs::File], [std::net::TcpStream] or [std::io::Cursor] and passes operations along,
+/// except when it decides to interfere.
+///
+/// Each call to `read`, `write`, `flush` or `seek` is subject to an [Outcome], either taken
+/// from a script, after which the Saboteur lets everything through, or chosen at random from a
+/// list of faults with a fixed chance. An `Err` Outcome fails without involving the inner
+/// stream at all, while `Ok(n)` lets no more than `n` bytes through. So `Ok(0)` reads look
+/// like the end of the stream, writes accept nothing and flushes are quietly dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Outcome, Saboteur};
+/// use std::io::{Cursor, ErrorKind, Read};
+/// let script = [Outcome::Ok(3), Outcome::Err(ErrorKind::ConnectionReset)];
+/// let mut saboteur = Saboteur::new(Cursor::new(b"Hello, World"), script);
+/// let mut buffer = [0u8; 5];
+/// assert_eq!(saboteur.read(&mut buffer).unwrap(), 3);
+/// let err = saboteur.read(&mut buffer).err().unwrap();
+/// assert_eq!(err.kind(), ErrorKind::ConnectionReset);
+/// assert_eq!(saboteur.read(&mut buffer).unwrap(), 5);
+/// assert_eq!(&buffer, b"lo, W");
+/// ```
+///
+/// ```
+/// # use misfortunate::{Outcome, Saboteur};
+/// use std::io::{ErrorKind, Write};
+/// let faults = vec![Outcome::Ok(1), Outcome::Err(ErrorKind::Interrupted)];
+/// let mut saboteur = Saboteur::new_random(Vec::new(), 1234, 2, faults);
+/// saboteur.write_all(b"Hello, World").unwrap();
+/// assert_eq!(saboteur.inner(), b"Hello, World");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Saboteur<T> {
+    inner: T,
+    schedule: Schedule,
+}
+
+impl<T> Saboteur<T> {
+    /// Constructs a new `Saboteur` around `inner`, which follows the `script` and then lets
+    /// everything through. Any [Plan] will do as a script, as will an array or [Vec] of Outcomes.
+    pub fn new<P: Into<Plan>>(inner: T, script: P) -> Saboteur<T> {
+        let schedule = Schedule::Script(script.into().into_iter());
+        Saboteur { inner, schedule }
+    }
+
+    /// Constructs a new `Saboteur` around `inner`, with a chance of one in `one_in` for each
+    /// operation that one of the `faults` (picked at random) happens instead. The same `seed`
+    /// always picks the same faults.
+    pub fn new_random(inner: T, seed: u64, one_in: u32, faults: Vec<Outcome>) -> Saboteur<T> {
+        let schedule = Schedule::Random {
+            prng: Prng::new(seed),
+            one_in,
+            faults,
+        };
+        Saboteur { inner, schedule }
+    }
+
+    /// The `inner` stream
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// The `inner` stream, which can be used without any interference
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    fn outcome(&mut self) -> Outcome {
+        let pass = Outcome::Ok(usize::MAX);
+        match &mut self.schedule {
+            Schedule::Script(script) => script.next().unwrap_or(pass),
+            Schedule::Random {
+                prng,
+                one_in,
+                faults,
+            } => {
+                if !faults.is_empty() && prng.one_in(*one_in) {
+                    faults[prng.below(faults.len())]
+                } else {
+                    pass
+                }
+            }
+        }
+    }
+}
+
+impl<T: Read> Read for Saboteur<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.outcome() {
+            Outcome::Err(kind) => Err(Error::from(kind)),
+            Outcome::Ok(0) => Ok(0),
+            Outcome::Ok(n) => {
+                let limit = buf.len().min(n);
+                self.inner.read(&mut buf[..limit])
+            }
+        }
+    }
+}
+
+impl<T: Write> Write for Saboteur<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.outcome() {
+            Outcome::Err(kind) => Err(Error::from(kind)),
+            Outcome::Ok(0) => Ok(0),
+            Outcome::Ok(n) => {
+                let limit = buf.len().min(n);
+                self.inner.write(&buf[..limit])
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.outcome() {
+            Outcome::Err(kind) => Err(Error::from(kind)),
+            Outcome::Ok(0) => Ok(()),
+            Outcome::Ok(_) => self.inner.flush(),
+        }
+    }
+}
+
+impl<T: Seek> Seek for Saboteur<T> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self.outcome() {
+            Outcome::Err(kind) => Err(Error::from(kind)),
+            Outcome::Ok(_) => self.inner.seek(pos),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufWriter, Cursor, ErrorKind};
+
+    #[test]
+    fn create() {
+        let s = Saboteur::new(Vec::<u8>::new(), []);
+        assert!(s.inner().is_empty());
+    }
+
+    #[test]
+    fn passes_through() {
+        let mut s = Saboteur::new(Cursor::new(b"Hello, World".to_vec()), []);
+        let mut text = String::new();
+        s.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "Hello, World");
+        s.seek(SeekFrom::Start(0)).unwrap();
+        s.write_all(b"Jello").unwrap();
+        s.flush().unwrap();
+        assert_eq!(s.inner().get_ref(), b"Jello, World");
+    }
+
+    #[test]
+    fn early_eof() {
+        let mut s = Saboteur::new(&b"Hello, World"[..], [Outcome::Ok(5), Outcome::Ok(0)]);
+        let mut text = String::new();
+        s.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "Hello");
+    }
+
+    #[test]
+    fn write_zero() {
+        let mut s = Saboteur::new(Vec::new(), [Outcome::Ok(2), Outcome::Ok(0)]);
+        let err = s.write_all(b"Hello").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+        assert_eq!(s.inner(), b"He");
+    }
+
+    #[test]
+    fn dropped_flush() {
+        let script = [Outcome::Ok(usize::MAX), Outcome::Ok(0)];
+        let mut s = Saboteur::new(BufWriter::new(Vec::new()), script);
+        s.write_all(b"Hello").unwrap();
+        s.flush().unwrap();
+        assert!(s.inner().get_ref().is_empty());
+        s.flush().unwrap();
+        assert_eq!(s.inner().get_ref(), b"Hello");
+    }
+
+    #[test]
+    fn seek_fails() {
+        let script = [Outcome::Err(ErrorKind::Unsupported)];
+        let mut s = Saboteur::new(Cursor::new(b"Hello"), script);
+        let err = s.seek(SeekFrom::End(0)).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        assert_eq!(s.seek(SeekFrom::End(0)).unwrap(), 5);
+    }
+
+    #[test]
+    fn random() {
+        let faults = vec![Outcome::Err(ErrorKind::TimedOut)];
+        let mut a = Saboteur::new_random(Vec::new(), 42, 3, faults.clone());
+        let mut b = Saboteur::new_random(Vec::new(), 42, 3, faults);
+        let mut failures = 0;
+        for _ in 0..100 {
+            let result = a.write(b"x");
+            assert_eq!(result.is_ok(), b.write(b"x").is_ok());
+            if result.is_err() {
+                failures += 1;
+            }
+        }
+        assert!(failures > 0 && failures < 100);
+        assert_eq!(a.inner().len(), 100 - failures);
+    }
+}
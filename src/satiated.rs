@@ -0,0 +1,80 @@
+/// `Satiated` implements [Extend] and [std::iter::FromIterator] but once it holds `N` items it
+/// has had enough, and simply stops pulling from the iterator. Anything left over is still
+/// waiting in the caller's iterator, which is thus only partly consumed.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Satiated;
+/// let mut letters = "abcdef".chars();
+/// let full: Satiated<char, 4> = letters.by_ref().collect();
+/// assert_eq!(full.inner(), &['a', 'b', 'c', 'd']);
+/// assert_eq!(letters.next(), Some('e'));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Satiated<T, const N: usize> {
+    eaten: Vec<T>,
+}
+
+impl<T, const N: usize> Satiated<T, N> {
+    /// Constructs a new, empty `Satiated` with an appetite for `N` items
+    pub fn new() -> Satiated<T, N> {
+        Satiated { eaten: Vec::new() }
+    }
+
+    /// The items this `Satiated` ate
+    pub fn inner(&self) -> &[T] {
+        &self.eaten
+    }
+}
+
+impl<T, const N: usize> Default for Satiated<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for Satiated<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let appetite = N - self.eaten.len();
+        self.eaten.extend(iter.into_iter().take(appetite));
+    }
+}
+
+impl<T, const N: usize> std::iter::FromIterator<T> for Satiated<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut satiated = Satiated::new();
+        satiated.extend(iter);
+        satiated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let s: Satiated<u8, 4> = Satiated::new();
+        assert!(s.inner().is_empty());
+    }
+
+    #[test]
+    fn leaves_leftovers() {
+        let mut numbers = 1..=10;
+        let mut s: Satiated<u8, 3> = Satiated::new();
+        s.extend(numbers.by_ref());
+        assert_eq!(s.inner(), &[1, 2, 3]);
+        s.extend(numbers.by_ref());
+        assert_eq!(s.inner(), &[1, 2, 3]);
+        assert_eq!(numbers.next(), Some(4));
+    }
+
+    #[test]
+    fn never_hungry() {
+        let mut numbers = 1..=10;
+        let s: Satiated<u8, 0> = numbers.by_ref().collect();
+        assert!(s.inner().is_empty());
+        assert_eq!(numbers.next(), Some(1));
+    }
+}
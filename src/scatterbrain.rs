@@ -0,0 +1,261 @@
+use std::io;
+use std::io::{IoSlice, IoSliceMut, Read, Write};
+
+/// How a [Scatterbrain] gets vectored I/O wrong
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Muddle {
+    /// Only about half of the first non-empty slice is transferred, and that is all reported
+    Partial,
+    /// Everything possible is transferred, but one more byte than that is reported
+    Overclaim,
+    /// The slices are transferred last first, and the total is reported honestly
+    Shuffle,
+}
+
+/// `Scatterbrain` wraps any [std::io::Read] or [std::io::Write] and passes along `read` and
+/// `write` faithfully, but it also overrides `read_vectored` and `write_vectored`, which get
+/// muddled, in a way that the ordinary methods do not.
+///
+/// If the inner stream fails part way through, the bytes already transferred are still
+/// reported, just as the standard library's own loops do.
+///
+/// Ideally a Scatterbrain would also claim to be good at vectored I/O, but
+/// `is_read_vectored` and `is_write_vectored` are not yet stable Rust.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Muddle, Scatterbrain};
+/// use std::io::{IoSlice, Write};
+/// let mut muddled = Scatterbrain::new(Vec::new(), Muddle::Shuffle);
+/// let slices = [IoSlice::new(b"Hello, "), IoSlice::new(b"World")];
+/// assert_eq!(muddled.write_vectored(&slices).unwrap(), 12);
+/// muddled.write_all(b"!").unwrap();
+/// assert_eq!(muddled.inner(), b"WorldHello, !");
+/// ```
+///
+/// ```
+/// # use misfortunate::{Muddle, Scatterbrain};
+/// use std::io::{IoSliceMut, Read};
+/// let mut muddled = Scatterbrain::new(&b"Hello"[..], Muddle::Overclaim);
+/// let mut first = [0u8; 3];
+/// let mut second = [0u8; 3];
+/// let mut slices = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+/// assert_eq!(muddled.read_vectored(&mut slices).unwrap(), 6);
+/// assert_eq!(&first, b"Hel");
+/// assert_eq!(&second, b"lo\0");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Scatterbrain<T> {
+    inner: T,
+    muddle: Muddle,
+}
+
+impl<T> Scatterbrain<T> {
+    /// Constructs a new `Scatterbrain` around `inner`, which gets vectored I/O wrong in the
+    /// way described by `muddle`
+    pub fn new(inner: T, muddle: Muddle) -> Scatterbrain<T> {
+        Scatterbrain { inner, muddle }
+    }
+
+    /// The `inner` reader or writer
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+fn overclaim(total: usize) -> usize {
+    if total == 0 {
+        0
+    } else {
+        total.saturating_add(1)
+    }
+}
+
+impl<T: Read> Read for Scatterbrain<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        if self.muddle == Muddle::Partial {
+            return match bufs.iter_mut().find(|buf| !buf.is_empty()) {
+                Some(buf) => {
+                    let half = buf.len().div_ceil(2);
+                    self.inner.read(&mut buf[..half])
+                }
+                None => Ok(0),
+            };
+        }
+
+        let mut total = 0;
+        let mut fill = |buf: &mut IoSliceMut<'_>| -> io::Result<bool> {
+            let n = match self.inner.read(buf) {
+                Ok(n) => n,
+                Err(_) if total > 0 => return Ok(false),
+                Err(e) => return Err(e),
+            };
+            total += n;
+            Ok(n == buf.len())
+        };
+        if self.muddle == Muddle::Shuffle {
+            for buf in bufs.iter_mut().rev() {
+                if !fill(buf)? {
+                    break;
+                }
+            }
+            Ok(total)
+        } else {
+            for buf in bufs.iter_mut() {
+                if !fill(buf)? {
+                    break;
+                }
+            }
+            Ok(overclaim(total))
+        }
+    }
+}
+
+impl<T: Write> Write for Scatterbrain<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        if self.muddle == Muddle::Partial {
+            return match bufs.iter().find(|buf| !buf.is_empty()) {
+                Some(buf) => {
+                    let half = buf.len().div_ceil(2);
+                    self.inner.write(&buf[..half])
+                }
+                None => Ok(0),
+            };
+        }
+
+        let mut total = 0;
+        let mut send = |buf: &IoSlice<'_>| -> io::Result<bool> {
+            let n = match self.inner.write(buf) {
+                Ok(n) => n,
+                Err(_) if total > 0 => return Ok(false),
+                Err(e) => return Err(e),
+            };
+            total += n;
+            Ok(n == buf.len())
+        };
+        if self.muddle == Muddle::Shuffle {
+            for buf in bufs.iter().rev() {
+                if !send(buf)? {
+                    break;
+                }
+            }
+            Ok(total)
+        } else {
+            for buf in bufs.iter() {
+                if !send(buf)? {
+                    break;
+                }
+            }
+            Ok(overclaim(total))
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Outcome, Saboteur};
+    use std::io::ErrorKind;
+
+    #[test]
+    fn create() {
+        let s = Scatterbrain::new(Vec::<u8>::new(), Muddle::Partial);
+        assert!(s.inner().is_empty());
+    }
+
+    #[test]
+    fn scalar() {
+        let mut s = Scatterbrain::new(Vec::new(), Muddle::Overclaim);
+        assert_eq!(s.write(b"Hello").unwrap(), 5);
+        s.write_all(b", World").unwrap();
+        assert_eq!(s.inner(), b"Hello, World");
+    }
+
+    #[test]
+    fn partial_write() {
+        let mut s = Scatterbrain::new(Vec::new(), Muddle::Partial);
+        let slices = [IoSlice::new(b""), IoSlice::new(b"abcde"), IoSlice::new(b"fg")];
+        assert_eq!(s.write_vectored(&slices).unwrap(), 3);
+        assert_eq!(s.inner(), b"abc");
+    }
+
+    #[test]
+    fn partial_read() {
+        let mut s = Scatterbrain::new(&b"Hello, World"[..], Muddle::Partial);
+        let mut first = [0u8; 4];
+        let mut second = [0u8; 4];
+        let mut slices = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+        assert_eq!(s.read_vectored(&mut slices).unwrap(), 2);
+        assert_eq!(&first, b"He\0\0");
+    }
+
+    #[test]
+    fn overclaim_write() {
+        let mut s = Scatterbrain::new(Vec::new(), Muddle::Overclaim);
+        let slices = [IoSlice::new(b"abc"), IoSlice::new(b"de")];
+        assert_eq!(s.write_vectored(&slices).unwrap(), 6);
+        assert_eq!(s.write_vectored(&[]).unwrap(), 0);
+        assert_eq!(s.inner(), b"abcde");
+    }
+
+    #[test]
+    #[should_panic]
+    fn advance_slices() {
+        let mut s = Scatterbrain::new(Vec::new(), Muddle::Overclaim);
+        let mut slices = [IoSlice::new(b"abc"), IoSlice::new(b"de")];
+        let mut remaining = &mut slices[..];
+        let n = s.write_vectored(remaining).unwrap();
+        // Panics because Scatterbrain claims to have written more than it was given
+        IoSlice::advance_slices(&mut remaining, n);
+    }
+
+    #[test]
+    fn shuffle_read() {
+        let mut s = Scatterbrain::new(&b"abcde"[..], Muddle::Shuffle);
+        let mut first = [0u8; 3];
+        let mut second = [0u8; 3];
+        let mut slices = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+        assert_eq!(s.read_vectored(&mut slices).unwrap(), 5);
+        assert_eq!(&first, b"de\0");
+        assert_eq!(&second, b"abc");
+    }
+
+    #[test]
+    fn failing_write() {
+        let script = [Outcome::Ok(usize::MAX), Outcome::Err(ErrorKind::BrokenPipe)];
+        let mut s = Scatterbrain::new(Saboteur::new(Vec::new(), script), Muddle::Shuffle);
+        let slices = [IoSlice::new(b"abc"), IoSlice::new(b"de")];
+        assert_eq!(s.write_vectored(&slices).unwrap(), 2);
+        assert_eq!(s.inner().inner(), b"de");
+        let script = [Outcome::Err(ErrorKind::BrokenPipe)];
+        let mut s = Scatterbrain::new(Saboteur::new(Vec::new(), script), Muddle::Overclaim);
+        let err = s.write_vectored(&slices).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn failing_read() {
+        let script = [Outcome::Ok(usize::MAX), Outcome::Err(ErrorKind::ConnectionReset)];
+        let inner = Saboteur::new(&b"abcdef"[..], script);
+        let mut s = Scatterbrain::new(inner, Muddle::Overclaim);
+        let mut first = [0u8; 3];
+        let mut second = [0u8; 3];
+        let mut slices = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+        assert_eq!(s.read_vectored(&mut slices).unwrap(), 4);
+        assert_eq!(&first, b"abc");
+        assert_eq!(&second, b"\0\0\0");
+    }
+}
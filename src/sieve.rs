@@ -0,0 +1,88 @@
+/// `Sieve` implements [Extend] and [std::iter::FromIterator] by consuming everything it is
+/// given, but it only keeps every other item, starting with the first. This alternation
+/// carries on across separate calls to `extend`.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Sieve;
+/// let mut sieve: Sieve<char> = "abcde".chars().collect();
+/// assert_eq!(sieve.inner(), &['a', 'c', 'e']);
+/// sieve.extend("fgh".chars());
+/// assert_eq!(sieve.inner(), &['a', 'c', 'e', 'g']);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sieve<T> {
+    kept: Vec<T>,
+    skip: bool,
+}
+
+impl<T> Sieve<T> {
+    /// Constructs a new, empty `Sieve`
+    pub fn new() -> Sieve<T> {
+        Sieve {
+            kept: Vec::new(),
+            skip: false,
+        }
+    }
+
+    /// The items this `Sieve` kept
+    pub fn inner(&self) -> &[T] {
+        &self.kept
+    }
+}
+
+impl<T> Default for Sieve<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for Sieve<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            if !self.skip {
+                self.kept.push(item);
+            }
+            self.skip = !self.skip;
+        }
+    }
+}
+
+impl<T> std::iter::FromIterator<T> for Sieve<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut sieve = Sieve::new();
+        sieve.extend(iter);
+        sieve
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let sieve: Sieve<u8> = Sieve::new();
+        assert!(sieve.inner().is_empty());
+    }
+
+    #[test]
+    fn consumes_everything() {
+        let mut numbers = 1..=10;
+        let mut sieve = Sieve::new();
+        sieve.extend(numbers.by_ref());
+        assert_eq!(numbers.next(), None);
+        assert_eq!(sieve.inner(), &[1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn alternates_across_calls() {
+        let mut sieve = Sieve::new();
+        sieve.extend(Some(1));
+        sieve.extend(Some(2));
+        sieve.extend(Some(3));
+        sieve.extend(Some(4));
+        assert_eq!(sieve.inner(), &[1, 3]);
+    }
+}
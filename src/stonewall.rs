@@ -0,0 +1,54 @@
+use std::io;
+use std::io::Write;
+
+/// `Stonewall` implements [std::io::Write] by successfully writing nothing, every time.
+/// This is allowed, [Write::write] returning `Ok(0)` is how a writer says it can't accept any
+/// more, and [Write::write_all] will turn it into a [std::io::ErrorKind::WriteZero] error,
+/// but a hand-written loop which just tries again will never finish.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Stonewall;
+/// use std::io::{ErrorKind, Write};
+/// let mut wall = Stonewall;
+/// let buffer = [42u8; 1024];
+/// assert_eq!(wall.write(&buffer).unwrap(), 0);
+/// let err = wall.write_all(&buffer).err().unwrap();
+/// assert_eq!(err.kind(), ErrorKind::WriteZero);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Stonewall;
+
+impl Write for Stonewall {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Ok(0)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn writing() {
+        let mut wall = Stonewall;
+        let buffer = [42u8; 1024];
+        assert_eq!(wall.write(&buffer).unwrap(), 0);
+        assert_eq!(wall.write(&buffer).unwrap(), 0);
+        assert!(wall.flush().is_ok());
+    }
+
+    #[test]
+    fn write_zero() {
+        let mut wall = Stonewall;
+        let err = write!(wall, "{} {} {}", 1, 2, 3).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+        assert!(wall.write_all(b"").is_ok());
+    }
+}
@@ -0,0 +1,90 @@
+/// `Tantrum` implements [Extend] and [std::iter::FromIterator] and will quietly store up to `N`
+/// items. If it is offered any more than that, it panics, dropping the item it was offered.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Tantrum;
+/// let calm: Tantrum<u8, 3> = (1..=3).collect();
+/// assert_eq!(calm.inner(), &[1, 2, 3]);
+/// let result = std::panic::catch_unwind(|| (1..=4).collect::<Tantrum<u8, 3>>());
+/// assert!(result.is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tantrum<T, const N: usize> {
+    kept: Vec<T>,
+}
+
+impl<T, const N: usize> Tantrum<T, N> {
+    /// Constructs a new, empty `Tantrum` which will tolerate `N` items
+    pub fn new() -> Tantrum<T, N> {
+        Tantrum { kept: Vec::new() }
+    }
+
+    /// The items this `Tantrum` kept
+    pub fn inner(&self) -> &[T] {
+        &self.kept
+    }
+}
+
+impl<T, const N: usize> Default for Tantrum<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for Tantrum<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            if self.kept.len() == N {
+                panic!("Tantrum can't cope with more than {N} items");
+            }
+            self.kept.push(item);
+        }
+    }
+}
+
+impl<T, const N: usize> std::iter::FromIterator<T> for Tantrum<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tantrum = Tantrum::new();
+        tantrum.extend(iter);
+        tantrum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let t: Tantrum<u8, 4> = Tantrum::new();
+        assert!(t.inner().is_empty());
+    }
+
+    #[test]
+    fn calm() {
+        let mut t: Tantrum<u8, 4> = Tantrum::new();
+        t.extend(1..=2);
+        t.extend(3..=4);
+        assert_eq!(t.inner(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn tantrum() {
+        let mut t: Tantrum<u8, 4> = Tantrum::new();
+        t.extend(1..=2);
+        t.extend(3..=5);
+    }
+
+    #[test]
+    fn keeps_what_it_had() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut t: Tantrum<u8, 2> = Tantrum::new();
+        let result = catch_unwind(AssertUnwindSafe(|| t.extend(1..=5)));
+        assert!(result.is_err());
+        assert_eq!(t.inner(), &[1, 2]);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::prng::Prng;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io;
+use std::io::{Read, Write};
+use std::rc::Rc;
+
+/// `Noise` decides how badly a [Telephone] line garbles what passes through it. Each field is
+/// a chance of one in that many, with zero meaning never. The default is a perfectly clear line.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Noise {
+    /// Chance, for each byte, that one of its bits is flipped
+    pub flip: u32,
+    /// Chance, for each byte, that it is lost
+    pub drop: u32,
+    /// Chance, for each byte, that it arrives twice
+    pub duplicate: u32,
+    /// Chance, for each write, that its bytes are held back and arrive after the next write
+    pub reorder: u32,
+}
+
+/// `Mishap` is a record of one thing a [Telephone] line did to the bytes written to it.
+/// Offsets count the bytes written to the [Mouthpiece], from zero.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Mishap {
+    /// The byte at this offset had this bit (0 to 7) flipped
+    Flipped { offset: usize, bit: u8 },
+    /// The byte at this offset was lost
+    Dropped { offset: usize },
+    /// The byte at this offset arrived twice
+    Duplicated { offset: usize },
+    /// The write starting at this offset, of this many bytes, arrived after the next write
+    Reordered { offset: usize, len: usize },
+}
+
+#[derive(Debug)]
+struct Line {
+    noise: Noise,
+    prng: Prng,
+    written: usize,
+    waiting: VecDeque<u8>,
+    held: Vec<u8>,
+    mishaps: Vec<Mishap>,
+}
+
+impl Line {
+    fn garble(&mut self, buf: &[u8]) -> Vec<u8> {
+        let mut garbled = Vec::with_capacity(buf.len());
+        for (offset, &byte) in (self.written..).zip(buf) {
+            if self.prng.one_in(self.noise.drop) {
+                self.mishaps.push(Mishap::Dropped { offset });
+                continue;
+            }
+            let mut byte = byte;
+            if self.prng.one_in(self.noise.flip) {
+                let bit = self.prng.below(8) as u8;
+                byte ^= 1 << bit;
+                self.mishaps.push(Mishap::Flipped { offset, bit });
+            }
+            garbled.push(byte);
+            if self.prng.one_in(self.noise.duplicate) {
+                garbled.push(byte);
+                self.mishaps.push(Mishap::Duplicated { offset });
+            }
+        }
+        garbled
+    }
+
+    fn release(&mut self) {
+        self.waiting.extend(self.held.drain(..));
+    }
+}
+
+/// `Telephone` is an in-memory pipe which garbles what passes through it. Bytes written to its
+/// [Mouthpiece] can be read from its [Earpiece], but on the way [Noise] may flip their bits, lose
+/// them, repeat them or deliver a whole write late. The same `seed` always garbles the same way,
+/// and every [Mishap] is logged, so a test can check that damage was noticed.
+///
+/// Writes always claim complete success, because a real noisy line doesn't know what it did.
+/// Whenever nothing is waiting the Earpiece reports the end of the stream. A write held back
+/// for reordering only arrives after the next write, or when the Mouthpiece is flushed.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Mishap, Noise, Telephone};
+/// use std::io::{Read, Write};
+/// let noise = Noise { flip: 4, ..Noise::default() };
+/// let telephone = Telephone::new(noise, 1234);
+/// telephone.mouthpiece().write_all(b"Hello, World").unwrap();
+/// let mut heard = Vec::new();
+/// telephone.earpiece().read_to_end(&mut heard).unwrap();
+/// assert_eq!(heard.len(), 12);
+/// for mishap in telephone.mishaps() {
+///     if let Mishap::Flipped { offset, bit } = mishap {
+///         assert_eq!(heard[offset] ^ (1 << bit), b"Hello, World"[offset]);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Telephone {
+    line: Rc<RefCell<Line>>,
+}
+
+impl Telephone {
+    /// Constructs a new `Telephone` line with this `noise`, garbling according to the `seed`
+    pub fn new(noise: Noise, seed: u64) -> Telephone {
+        let line = Line {
+            noise,
+            prng: Prng::new(seed),
+            written: 0,
+            waiting: VecDeque::new(),
+            held: Vec::new(),
+            mishaps: Vec::new(),
+        };
+        Telephone {
+            line: Rc::new(RefCell::new(line)),
+        }
+    }
+
+    /// A [Mouthpiece] for writing into this line
+    pub fn mouthpiece(&self) -> Mouthpiece {
+        Mouthpiece {
+            line: self.line.clone(),
+        }
+    }
+
+    /// An [Earpiece] for reading what comes out of this line
+    pub fn earpiece(&self) -> Earpiece {
+        Earpiece {
+            line: self.line.clone(),
+        }
+    }
+
+    /// Every [Mishap] so far, in order
+    pub fn mishaps(&self) -> Vec<Mishap> {
+        self.line.borrow().mishaps.clone()
+    }
+}
+
+/// `Mouthpiece` is the [std::io::Write] end of a [Telephone]
+#[derive(Clone, Debug)]
+pub struct Mouthpiece {
+    line: Rc<RefCell<Line>>,
+}
+
+impl Write for Mouthpiece {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let line = &mut *self.line.borrow_mut();
+        let garbled = line.garble(buf);
+        let offset = line.written;
+        line.written += buf.len();
+        if !line.held.is_empty() {
+            line.waiting.extend(garbled);
+            line.release();
+        } else if !garbled.is_empty() && line.prng.one_in(line.noise.reorder) {
+            line.held = garbled;
+            line.mishaps.push(Mishap::Reordered {
+                offset,
+                len: buf.len(),
+            });
+        } else {
+            line.waiting.extend(garbled);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.line.borrow_mut().release();
+        Ok(())
+    }
+}
+
+/// `Earpiece` is the [std::io::Read] end of a [Telephone]
+#[derive(Clone, Debug)]
+pub struct Earpiece {
+    line: Rc<RefCell<Line>>,
+}
+
+impl Read for Earpiece {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut line = self.line.borrow_mut();
+        let n = line.waiting.len().min(buf.len());
+        for (slot, byte) in buf.iter_mut().zip(line.waiting.drain(..n)) {
+            *slot = byte;
+        }
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+    fn call(noise: Noise, seed: u64, chunks: &[&[u8]]) -> (Vec<u8>, Vec<Mishap>) {
+        let telephone = Telephone::new(noise, seed);
+        let mut mouthpiece = telephone.mouthpiece();
+        for chunk in chunks {
+            mouthpiece.write_all(chunk).unwrap();
+        }
+        mouthpiece.flush().unwrap();
+        let mut heard = Vec::new();
+        telephone.earpiece().read_to_end(&mut heard).unwrap();
+        (heard, telephone.mishaps())
+    }
+
+    #[test]
+    fn clear_line() {
+        let (heard, mishaps) = call(Noise::default(), 0, &[MESSAGE]);
+        assert_eq!(heard, MESSAGE);
+        assert!(mishaps.is_empty());
+    }
+
+    #[test]
+    fn repeatable() {
+        let noise = Noise {
+            flip: 5,
+            drop: 5,
+            duplicate: 5,
+            reorder: 2,
+        };
+        let chunks = [&MESSAGE[..10], &MESSAGE[10..20], &MESSAGE[20..]];
+        assert_eq!(call(noise, 99, &chunks), call(noise, 99, &chunks));
+    }
+
+    #[test]
+    fn flips() {
+        let noise = Noise {
+            flip: 3,
+            ..Noise::default()
+        };
+        let (heard, mishaps) = call(noise, 7, &[MESSAGE]);
+        assert!(!mishaps.is_empty());
+        let changed = heard.iter().zip(MESSAGE).filter(|(a, b)| a != b).count();
+        assert_eq!(changed, mishaps.len());
+    }
+
+    #[test]
+    fn drops_and_duplicates() {
+        let noise = Noise {
+            drop: 4,
+            ..Noise::default()
+        };
+        let (heard, mishaps) = call(noise, 7, &[MESSAGE]);
+        assert!(!mishaps.is_empty());
+        assert_eq!(heard.len(), MESSAGE.len() - mishaps.len());
+        let noise = Noise {
+            duplicate: 4,
+            ..Noise::default()
+        };
+        let (heard, mishaps) = call(noise, 7, &[MESSAGE]);
+        assert!(!mishaps.is_empty());
+        assert_eq!(heard.len(), MESSAGE.len() + mishaps.len());
+    }
+
+    #[test]
+    fn reorders() {
+        let noise = Noise {
+            reorder: 1,
+            ..Noise::default()
+        };
+        let (heard, mishaps) = call(noise, 7, &[b"one ", b"two ", b"three ", b"four "]);
+        assert_eq!(heard, b"two one four three ");
+        assert_eq!(
+            mishaps,
+            [
+                Mishap::Reordered { offset: 0, len: 4 },
+                Mishap::Reordered { offset: 8, len: 6 },
+            ]
+        );
+        let (heard, _) = call(noise, 7, &[b"alone"]);
+        assert_eq!(heard, b"alone");
+    }
+
+    #[test]
+    fn waiting() {
+        let telephone = Telephone::new(Noise::default(), 0);
+        let mut earpiece = telephone.earpiece();
+        let mut buffer = [0u8; 4];
+        assert_eq!(earpiece.read(&mut buffer).unwrap(), 0);
+        telephone.mouthpiece().write_all(b"Hello").unwrap();
+        assert_eq!(earpiece.read(&mut buffer).unwrap(), 4);
+        assert_eq!(&buffer, b"Hell");
+        assert_eq!(earpiece.read(&mut buffer).unwrap(), 1);
+    }
+}
@@ -0,0 +1,449 @@
+use crate::plan::kind_from_name;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::io::{Error, ErrorKind, Read, Write};
+use std::str::FromStr;
+
+/// `Call` is a single I/O operation and its result, as kept in a [Transcript]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Call {
+    /// A `read`, and the bytes it read or the kind of error
+    Read(Result<Vec<u8>, ErrorKind>),
+    /// A `read` which claimed to have read more bytes than fit in its buffer, with the bytes
+    /// that were actually in the buffer, and the number claimed
+    Overclaim(Vec<u8>, usize),
+    /// A `write`, and how many bytes it wrote or the kind of error
+    Write(Result<usize, ErrorKind>),
+    /// A `flush`, and whether it succeeded
+    Flush(Result<(), ErrorKind>),
+}
+
+/// `TranscriptError` explains which line of a [Transcript] could not be understood
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TranscriptError {
+    line: String,
+}
+
+impl fmt::Display for TranscriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unrecognised transcript line `{}`", self.line)
+    }
+}
+
+impl std::error::Error for TranscriptError {}
+
+/// `Transcript` is a record of I/O [Call]s, made by a [Stenographer] and played back by a
+/// [Parrot]. It can be edited before it is played back.
+///
+/// A Transcript is written as text with one call on each line, like `read ok 48656c6c6f`
+/// (the bytes read, in hex), `read claim 7 48656c6c6f` (a read which claimed 7 bytes,
+/// though only five fit), `write ok 5`, `flush ok` or `read err ConnectionReset`,
+/// which [FromStr] parses, and [fmt::Display] writes.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Transcript;
+/// use std::io::ErrorKind;
+/// let text = "read ok 48656c6c6f\nwrite ok 5\nflush ok\n";
+/// let mut transcript: Transcript = text.parse().unwrap();
+/// assert_eq!(transcript.to_string(), text);
+/// transcript.fail(1, ErrorKind::BrokenPipe);
+/// assert_eq!(transcript.to_string(), "read ok 48656c6c6f\nwrite err BrokenPipe\nflush ok\n");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Transcript {
+    calls: Vec<Call>,
+}
+
+impl Transcript {
+    /// The [Call]s in this `Transcript`, in order
+    pub fn calls(&self) -> &[Call] {
+        &self.calls
+    }
+
+    /// Edits the `Transcript` so that the call at this `index`, counting from zero, fails
+    /// with an error of this `kind` instead. Does nothing if there is no such call.
+    pub fn fail(&mut self, index: usize, kind: ErrorKind) {
+        if let Some(call) = self.calls.get_mut(index) {
+            *call = match call {
+                Call::Read(_) | Call::Overclaim(..) => Call::Read(Err(kind)),
+                Call::Write(_) => Call::Write(Err(kind)),
+                Call::Flush(_) => Call::Flush(Err(kind)),
+            };
+        }
+    }
+}
+
+fn decode(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| hex.get(i..i + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+        .collect()
+}
+
+impl FromStr for Transcript {
+    type Err = TranscriptError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut calls = Vec::new();
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let error = || TranscriptError {
+                line: line.to_owned(),
+            };
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let call = match words[..] {
+                ["read", "ok"] => Call::Read(Ok(Vec::new())),
+                ["read", "ok", hex] => Call::Read(Ok(decode(hex).ok_or_else(error)?)),
+                ["read", "claim", n] => {
+                    Call::Overclaim(Vec::new(), n.parse().map_err(|_| error())?)
+                }
+                ["read", "claim", n, hex] => Call::Overclaim(
+                    decode(hex).ok_or_else(error)?,
+                    n.parse().map_err(|_| error())?,
+                ),
+                ["write", "ok", n] => Call::Write(Ok(n.parse().map_err(|_| error())?)),
+                ["flush", "ok"] => Call::Flush(Ok(())),
+                [op, "err", name] => {
+                    let kind = kind_from_name(name).ok_or_else(error)?;
+                    match op {
+                        "read" => Call::Read(Err(kind)),
+                        "write" => Call::Write(Err(kind)),
+                        "flush" => Call::Flush(Err(kind)),
+                        _ => return Err(error()),
+                    }
+                }
+                _ => return Err(error()),
+            };
+            calls.push(call);
+        }
+        Ok(Transcript { calls })
+    }
+}
+
+impl fmt::Display for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for call in &self.calls {
+            match call {
+                Call::Read(Ok(data)) if data.is_empty() => writeln!(f, "read ok")?,
+                Call::Read(Ok(data)) => {
+                    f.write_str("read ok ")?;
+                    for byte in data {
+                        write!(f, "{byte:02x}")?;
+                    }
+                    writeln!(f)?;
+                }
+                Call::Read(Err(kind)) => writeln!(f, "read err {kind:?}")?,
+                Call::Overclaim(data, n) => {
+                    write!(f, "read claim {n}")?;
+                    if !data.is_empty() {
+                        f.write_str(" ")?;
+                        for byte in data {
+                            write!(f, "{byte:02x}")?;
+                        }
+                    }
+                    writeln!(f)?;
+                }
+                Call::Write(Ok(n)) => writeln!(f, "write ok {n}")?,
+                Call::Write(Err(kind)) => writeln!(f, "write err {kind:?}")?,
+                Call::Flush(Ok(())) => writeln!(f, "flush ok")?,
+                Call::Flush(Err(kind)) => writeln!(f, "flush err {kind:?}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::iter::FromIterator<Call> for Transcript {
+    fn from_iter<I: IntoIterator<Item = Call>>(iter: I) -> Self {
+        let calls = iter.into_iter().collect();
+        Transcript { calls }
+    }
+}
+
+/// `Stenographer` wraps any [std::io::Read] or [std::io::Write] and passes everything along
+/// faithfully, while keeping a [Transcript] of every `read`, `write` and `flush`.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Stenographer;
+/// use std::io::Write;
+/// let mut steno = Stenographer::new(Vec::new());
+/// steno.write_all(b"Hello").unwrap();
+/// steno.flush().unwrap();
+/// assert_eq!(steno.transcript().to_string(), "write ok 5\nflush ok\n");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Stenographer<T> {
+    inner: T,
+    transcript: Transcript,
+}
+
+impl<T> Stenographer<T> {
+    /// Constructs a new `Stenographer` around `inner`
+    pub fn new(inner: T) -> Stenographer<T> {
+        Stenographer {
+            inner,
+            transcript: Transcript::default(),
+        }
+    }
+
+    /// The [Transcript] of everything so far
+    pub fn transcript(&self) -> &Transcript {
+        &self.transcript
+    }
+
+    /// The `inner` reader or writer
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: Read> Read for Stenographer<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let result = self.inner.read(buf);
+        let call = match &result {
+            Ok(n) => match buf.get(..*n) {
+                Some(data) => Call::Read(Ok(data.to_vec())),
+                None => Call::Overclaim(buf.to_vec(), *n),
+            },
+            Err(e) => Call::Read(Err(e.kind())),
+        };
+        self.transcript.calls.push(call);
+        result
+    }
+}
+
+impl<T: Write> Write for Stenographer<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let result = self.inner.write(buf);
+        let call = Call::Write(result.as_ref().map(|&n| n).map_err(Error::kind));
+        self.transcript.calls.push(call);
+        result
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let result = self.inner.flush();
+        let call = Call::Flush(result.as_ref().map(|_| ()).map_err(Error::kind));
+        self.transcript.calls.push(call);
+        result
+    }
+}
+
+/// `Parrot` implements [std::io::Read] and [std::io::Write] by repeating a [Transcript].
+/// Each `read`, `write` or `flush` gets the result of the next call in the transcript.
+///
+/// If the transcript has a different kind of call next, the Parrot reports an error and stays
+/// where it is. If a read's buffer is too small, the rest of the data is kept for the next read.
+/// Once the transcript is finished, reads and writes get `Ok(0)` and flushes succeed.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Parrot, Stenographer};
+/// use std::io::{ErrorKind, Read};
+/// let mut steno = Stenographer::new(&b"Hello, World"[..]);
+/// let mut buffer = [0u8; 5];
+/// while steno.read(&mut buffer).unwrap() > 0 {}
+///
+/// let mut transcript = steno.transcript().clone();
+/// transcript.fail(1, ErrorKind::ConnectionReset);
+/// let mut parrot = Parrot::new(transcript);
+/// assert_eq!(parrot.read(&mut buffer).unwrap(), 5);
+/// assert_eq!(&buffer, b"Hello");
+/// let err = parrot.read(&mut buffer).err().unwrap();
+/// assert_eq!(err.kind(), ErrorKind::ConnectionReset);
+/// assert_eq!(parrot.read(&mut buffer).unwrap(), 2);
+/// assert_eq!(&buffer[..2], b"ld");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Parrot {
+    calls: VecDeque<Call>,
+}
+
+impl Parrot {
+    /// Constructs a new `Parrot` which will repeat the `transcript`
+    pub fn new(transcript: Transcript) -> Parrot {
+        Parrot {
+            calls: transcript.calls.into(),
+        }
+    }
+
+    fn confused(&mut self, call: Call) -> Error {
+        self.calls.push_front(call);
+        Error::other("Parrot transcript has a different call here")
+    }
+}
+
+impl Read for Parrot {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.calls.pop_front() {
+            Some(Call::Read(Ok(mut data))) => {
+                let n = data.len().min(buf.len());
+                buf[..n].copy_from_slice(&data[..n]);
+                if n < data.len() {
+                    data.drain(..n);
+                    self.calls.push_front(Call::Read(Ok(data)));
+                }
+                Ok(n)
+            }
+            Some(Call::Read(Err(kind))) => Err(Error::from(kind)),
+            Some(Call::Overclaim(data, claimed)) => {
+                let n = data.len().min(buf.len());
+                buf[..n].copy_from_slice(&data[..n]);
+                Ok(claimed)
+            }
+            Some(call) => Err(self.confused(call)),
+            None => Ok(0),
+        }
+    }
+}
+
+impl Write for Parrot {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.calls.pop_front() {
+            Some(Call::Write(Ok(n))) => Ok(n.min(buf.len())),
+            Some(Call::Write(Err(kind))) => Err(Error::from(kind)),
+            Some(call) => Err(self.confused(call)),
+            None => Ok(0),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.calls.pop_front() {
+            Some(Call::Flush(Ok(()))) | None => Ok(()),
+            Some(Call::Flush(Err(kind))) => Err(Error::from(kind)),
+            Some(call) => Err(self.confused(call)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Braggart, LoadLetter, Trickle};
+
+    #[test]
+    fn create() {
+        let t = Transcript::default();
+        assert!(t.calls().is_empty());
+        let s = Stenographer::new(Vec::<u8>::new());
+        assert!(s.inner().is_empty());
+        let _ = Parrot::new(t);
+    }
+
+    #[test]
+    fn recording() {
+        let mut steno = Stenographer::new(Trickle::new_limit(b"abc".to_vec(), 2));
+        let mut buffer = [0u8; 8];
+        assert_eq!(steno.read(&mut buffer).unwrap(), 2);
+        assert_eq!(steno.read(&mut buffer).unwrap(), 1);
+        assert_eq!(steno.read(&mut buffer).unwrap(), 0);
+        steno.write_all(b"xyz").unwrap();
+        steno.flush().unwrap();
+        assert_eq!(
+            steno.transcript().calls(),
+            [
+                Call::Read(Ok(b"ab".to_vec())),
+                Call::Read(Ok(b"c".to_vec())),
+                Call::Read(Ok(Vec::new())),
+                Call::Write(Ok(2)),
+                Call::Write(Ok(1)),
+                Call::Flush(Ok(())),
+            ]
+        );
+        assert_eq!(steno.inner().written(), b"xyz");
+    }
+
+    #[test]
+    fn recording_errors() {
+        let mut steno = Stenographer::new(LoadLetter::new(ErrorKind::TimedOut));
+        let mut buffer = [0u8; 8];
+        assert!(steno.read(&mut buffer).is_err());
+        assert!(steno.write(&buffer).is_err());
+        assert!(steno.flush().is_err());
+        assert_eq!(
+            steno.transcript().to_string(),
+            "read err TimedOut\nwrite err TimedOut\nflush err TimedOut\n"
+        );
+    }
+
+    #[test]
+    fn overclaim() {
+        let mut steno = Stenographer::new(Braggart::new(&b"Hello, World"[..], 3));
+        let mut buffer = [0u8; 4];
+        assert_eq!(steno.read(&mut buffer).unwrap(), 7);
+        let text = steno.transcript().to_string();
+        assert_eq!(text, "read claim 7 48656c6c\n");
+
+        let mut parrot = Parrot::new(text.parse().unwrap());
+        let mut buffer = [0u8; 2];
+        assert_eq!(parrot.read(&mut buffer).unwrap(), 7);
+        assert_eq!(&buffer, b"He");
+        assert_eq!(parrot.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn round_trip() {
+        let transcript: Transcript = [
+            Call::Read(Ok(vec![0, 1, 0xFE, 0xFF])),
+            Call::Read(Ok(Vec::new())),
+            Call::Overclaim(vec![0x2A], 7),
+            Call::Overclaim(Vec::new(), usize::MAX),
+            Call::Write(Ok(42)),
+            Call::Write(Err(ErrorKind::Interrupted)),
+            Call::Flush(Err(ErrorKind::StorageFull)),
+        ]
+        .into_iter()
+        .collect();
+        let text = transcript.to_string();
+        assert_eq!(text.parse(), Ok(transcript));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!("read ok 4".parse::<Transcript>().is_err());
+        assert!("read ok zz".parse::<Transcript>().is_err());
+        assert!("write ok".parse::<Transcript>().is_err());
+        assert!("seek ok".parse::<Transcript>().is_err());
+        assert!("seek err Other".parse::<Transcript>().is_err());
+        let err = "flush ok\nflush err Kaiju"
+            .parse::<Transcript>()
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "unrecognised transcript line `flush err Kaiju`"
+        );
+    }
+
+    #[test]
+    fn replay() {
+        let transcript: Transcript = "write ok 3\nwrite ok 2\nflush ok\n".parse().unwrap();
+        let mut parrot = Parrot::new(transcript);
+        let err = parrot.flush().err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Other);
+        parrot.write_all(b"Hello").unwrap();
+        parrot.flush().unwrap();
+        assert_eq!(parrot.write(b"Hello").unwrap(), 0);
+        let mut buffer = [0u8; 8];
+        assert_eq!(parrot.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn small_buffer() {
+        let transcript: Transcript = "read ok 616263".parse().unwrap();
+        let mut parrot = Parrot::new(transcript);
+        let mut buffer = [0u8; 2];
+        assert_eq!(parrot.read(&mut buffer).unwrap(), 2);
+        assert_eq!(&buffer, b"ab");
+        assert_eq!(parrot.read(&mut buffer).unwrap(), 1);
+        assert_eq!(&buffer[..1], b"c");
+        assert_eq!(parrot.read(&mut buffer).unwrap(), 0);
+    }
+}
@@ -0,0 +1,126 @@
+use std::io;
+use std::io::{Cursor, Read, Write};
+
+/// `Trickle` implements [std::io::Read] and [std::io::Write] correctly, reading from its
+/// `input` and keeping everything written to it, but it never moves more than a few bytes
+/// (by default just one) in a single call, no matter how large the buffer is.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Trickle;
+/// use std::io::Read;
+/// let mut trickle = Trickle::new(b"Hello".to_vec());
+/// let mut buffer = [0u8; 1024];
+/// assert_eq!(trickle.read(&mut buffer).unwrap(), 1);
+/// let mut rest = String::new();
+/// trickle.read_to_string(&mut rest).unwrap();
+/// assert_eq!(rest, "ello");
+/// ```
+///
+/// ```
+/// # use misfortunate::Trickle;
+/// use std::io::Write;
+/// let mut trickle = Trickle::new_limit(Vec::new(), 3);
+/// assert_eq!(trickle.write(b"Hello").unwrap(), 3);
+/// trickle.write_all(b"lo, World").unwrap();
+/// assert_eq!(trickle.written(), b"Hello, World");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Trickle {
+    input: Cursor<Vec<u8>>,
+    output: Vec<u8>,
+    limit: usize,
+}
+
+impl Trickle {
+    /// Constructs a new `Trickle` which reads `input` one byte at a time
+    pub fn new(input: Vec<u8>) -> Self {
+        Self::new_limit(input, 1)
+    }
+
+    /// Constructs a new `Trickle` which reads `input` at most `limit` bytes at a time.
+    /// Even a Trickle has to make progress, so a `limit` of zero is treated as one.
+    pub fn new_limit(input: Vec<u8>, limit: usize) -> Self {
+        Self {
+            input: Cursor::new(input),
+            output: Vec::new(),
+            limit: limit.max(1),
+        }
+    }
+
+    /// Everything written so far
+    pub fn written(&self) -> &[u8] {
+        &self.output
+    }
+}
+
+impl Read for Trickle {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let limit = buf.len().min(self.limit);
+        self.input.read(&mut buf[..limit])
+    }
+}
+
+impl Write for Trickle {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.limit);
+        self.output.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let t = Trickle::new(Vec::new());
+        assert!(t.written().is_empty());
+    }
+
+    #[test]
+    fn reading() {
+        let mut t = Trickle::new(b"abc".to_vec());
+        let mut buffer = [0u8; 1024];
+        assert_eq!(t.read(&mut buffer).unwrap(), 1);
+        assert_eq!(buffer[0], b'a');
+        assert_eq!(t.read(&mut buffer).unwrap(), 1);
+        assert_eq!(buffer[0], b'b');
+        assert_eq!(t.read(&mut buffer).unwrap(), 1);
+        assert_eq!(buffer[0], b'c');
+        assert_eq!(t.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn read_exact() {
+        let mut t = Trickle::new_limit(b"Hello, World".to_vec(), 5);
+        let mut buffer = [0u8; 7];
+        t.read_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer, b"Hello, ");
+    }
+
+    #[test]
+    fn writing() {
+        let mut t = Trickle::new_limit(Vec::new(), 0);
+        assert_eq!(t.write(b"abc").unwrap(), 1);
+        assert_eq!(t.write(b"").unwrap(), 0);
+        t.write_all(b"bc").unwrap();
+        t.flush().unwrap();
+        assert_eq!(t.written(), b"abc");
+    }
+
+    #[test]
+    fn copy() {
+        let mut source = Trickle::new_limit(b"Hello, World".to_vec(), 2);
+        let mut sink = Trickle::new_limit(Vec::new(), 3);
+        let n = io::copy(&mut source, &mut sink).unwrap();
+        assert_eq!(n, 12);
+        assert_eq!(sink.written(), b"Hello, World");
+    }
+}
@@ -0,0 +1,145 @@
+/// `Tripwire` wraps any [Iterator] and faithfully passes along its items, right up until the
+/// item at a chosen position is requested, at which point it panics. Once tripped it stays
+/// tripped, and every subsequent request panics too.
+///
+/// Sweeping the position over every possible value, with [std::panic::catch_unwind] around the
+/// consumer, is a classic way to check that code such as [Vec::extend] or
+/// [std::iter::FromIterator] implementations neither leak nor double drop when unwinding.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::Tripwire;
+/// use std::panic::catch_unwind;
+/// let mut wire = Tripwire::new(1..10, 2);
+/// assert_eq!(wire.next(), Some(1));
+/// assert_eq!(wire.next(), Some(2));
+/// let result = catch_unwind(move || wire.next());
+/// assert!(result.is_err());
+/// ```
+///
+/// ```
+/// # use misfortunate::Tripwire;
+/// use std::panic::catch_unwind;
+/// for trip in 0..=3 {
+///     let result = catch_unwind(|| Tripwire::new(vec!["a", "b", "c"].into_iter(), trip).count());
+///     assert!(result.is_err());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tripwire<I> {
+    inner: I,
+    trip: usize,
+    count: usize,
+}
+
+impl<I: Iterator> Tripwire<I> {
+    /// Constructs a new `Tripwire` which panics when asked for the item at position `trip`,
+    /// counting from zero. This happens even if `inner` would have no such item.
+    pub fn new(inner: I, trip: usize) -> Tripwire<I> {
+        Tripwire {
+            inner,
+            trip,
+            count: 0,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Tripwire<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.count == self.trip {
+            panic!("Tripwire tripped at item {}", self.trip);
+        }
+        self.count += 1;
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::collections::BinaryHeap;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Counted(u32, Rc<Cell<usize>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    fn sweep<F: Fn(Tripwire<std::vec::IntoIter<Counted>>)>(consume: F) {
+        const LENGTH: usize = 6;
+
+        for trip in 0..=LENGTH {
+            let drops = Rc::new(Cell::new(0));
+            let items: Vec<Counted> = (0..LENGTH as u32)
+                .map(|n| Counted(n, drops.clone()))
+                .collect();
+            let wire = Tripwire::new(items.into_iter(), trip);
+            let result = catch_unwind(AssertUnwindSafe(|| consume(wire)));
+            assert!(result.is_err());
+            assert_eq!(drops.get(), LENGTH);
+        }
+    }
+
+    #[test]
+    fn create() {
+        let _ = Tripwire::new("Rabbit".chars(), 4);
+    }
+
+    #[test]
+    fn faithful() {
+        let wire = Tripwire::new(1..=5, 5);
+        let taken: Vec<u8> = wire.take(5).collect();
+        assert_eq!(taken, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn tripped() {
+        let wire = Tripwire::new(1..=5, 5);
+        let _: Vec<u8> = wire.collect();
+    }
+
+    #[test]
+    fn stays_tripped() {
+        let mut wire = Tripwire::new(1..=5, 0);
+        let first = catch_unwind(AssertUnwindSafe(|| wire.next()));
+        assert!(first.is_err());
+        let second = catch_unwind(AssertUnwindSafe(|| wire.next()));
+        assert!(second.is_err());
+    }
+
+    #[test]
+    fn collect() {
+        sweep(|wire| {
+            let _: Vec<Counted> = wire.collect();
+        });
+    }
+
+    #[test]
+    fn extend() {
+        sweep(|wire| {
+            let mut v = Vec::new();
+            v.extend(wire);
+        });
+    }
+
+    #[test]
+    fn heap() {
+        sweep(|wire| {
+            let _: BinaryHeap<Counted> = wire.collect();
+        });
+    }
+}
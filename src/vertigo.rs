@@ -0,0 +1,171 @@
+use std::io;
+use std::io::{Read, Seek, SeekFrom};
+
+/// The lie a [Vertigo] tells about where it is
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Spin {
+    /// Every position reported is off by this much
+    Offset(i64),
+    /// Each call to `stream_position` reports one byte further along than the last
+    Drift,
+    /// Seeking relative to the end reports the offset it was given, wrapped as if unsigned
+    Wrap,
+    /// `rewind` does nothing at all
+    Stuck,
+}
+
+/// `Vertigo` wraps anything which implements [std::io::Seek] and actually seeks it exactly
+/// as asked, but it can't be trusted to say where it ended up, according to its [Spin].
+/// It passes along [std::io::Read] so that it can stand in for a file or [std::io::Cursor].
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Spin, Vertigo};
+/// use std::io::{Cursor, Read, Seek, SeekFrom};
+/// let mut dizzy = Vertigo::new(Cursor::new(b"Hello, World"), Spin::Offset(-3));
+/// assert_eq!(dizzy.seek(SeekFrom::Start(7)).unwrap(), 4);
+/// let mut text = String::new();
+/// dizzy.read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "World");
+/// ```
+///
+/// ```
+/// # use misfortunate::{Spin, Vertigo};
+/// use std::io::{Cursor, Seek, SeekFrom};
+/// let mut dizzy = Vertigo::new(Cursor::new(b"Hello, World"), Spin::Wrap);
+/// assert_eq!(dizzy.seek(SeekFrom::End(-5)).unwrap(), u64::MAX - 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Vertigo<T> {
+    inner: T,
+    spin: Spin,
+    drift: u64,
+}
+
+impl<T: Seek> Vertigo<T> {
+    /// Constructs a new `Vertigo` around `inner`, which lies according to `spin`
+    pub fn new(inner: T, spin: Spin) -> Vertigo<T> {
+        Vertigo {
+            inner,
+            spin,
+            drift: 0,
+        }
+    }
+
+    /// The `inner` seeker, which knows where it really is
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// The `inner` seeker, which can be used without any lies
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: Seek> Seek for Vertigo<T> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let actual = self.inner.seek(pos)?;
+        Ok(match (self.spin, pos) {
+            (Spin::Offset(offset), _) => actual.wrapping_add_signed(offset),
+            (Spin::Wrap, SeekFrom::End(offset)) => offset as u64,
+            _ => actual,
+        })
+    }
+
+    #[allow(clippy::seek_from_current)]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        let position = self.seek(SeekFrom::Current(0))?;
+        if self.spin == Spin::Drift {
+            let drift = self.drift;
+            self.drift += 1;
+            Ok(position.wrapping_add(drift))
+        } else {
+            Ok(position)
+        }
+    }
+
+    fn rewind(&mut self) -> io::Result<()> {
+        if self.spin == Spin::Stuck {
+            Ok(())
+        } else {
+            self.inner.rewind()
+        }
+    }
+}
+
+impl<T: Read> Read for Vertigo<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const TEXT: &[u8] = b"Hello, World";
+
+    #[test]
+    fn create() {
+        let v = Vertigo::new(Cursor::new(TEXT), Spin::Drift);
+        assert_eq!(v.inner().position(), 0);
+    }
+
+    #[test]
+    fn offset() {
+        let mut v = Vertigo::new(Cursor::new(TEXT), Spin::Offset(100));
+        assert_eq!(v.seek(SeekFrom::End(-5)).unwrap(), 107);
+        assert_eq!(v.stream_position().unwrap(), 107);
+        assert_eq!(v.inner().position(), 7);
+        assert_eq!(v.inner_mut().stream_position().unwrap(), 7);
+        let mut rest = Vec::new();
+        v.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"World");
+    }
+
+    #[test]
+    #[allow(clippy::seek_from_current)]
+    fn drift() {
+        let mut v = Vertigo::new(Cursor::new(TEXT), Spin::Drift);
+        assert_eq!(v.seek(SeekFrom::Start(4)).unwrap(), 4);
+        assert_eq!(v.stream_position().unwrap(), 4);
+        assert_eq!(v.stream_position().unwrap(), 5);
+        assert_eq!(v.stream_position().unwrap(), 6);
+        assert_eq!(v.inner().position(), 4);
+        v.inner_mut().set_position(2);
+        assert_eq!(v.seek(SeekFrom::Current(0)).unwrap(), 2);
+    }
+
+    #[test]
+    fn wrap() {
+        let mut v = Vertigo::new(Cursor::new(TEXT), Spin::Wrap);
+        assert_eq!(v.seek(SeekFrom::End(-1)).unwrap(), u64::MAX);
+        assert_eq!(v.stream_position().unwrap(), 11);
+        assert_eq!(v.seek(SeekFrom::End(0)).unwrap(), 0);
+        assert_eq!(v.inner().position(), 12);
+        assert_eq!(v.inner_mut().seek(SeekFrom::End(-1)).unwrap(), 11);
+    }
+
+    #[test]
+    fn stuck() {
+        let mut v = Vertigo::new(Cursor::new(TEXT), Spin::Stuck);
+        let mut first = [0u8; 5];
+        v.read_exact(&mut first).unwrap();
+        v.rewind().unwrap();
+        let mut second = [0u8; 5];
+        v.read_exact(&mut second).unwrap();
+        assert_eq!(&first, b"Hello");
+        assert_eq!(&second, b", Wor");
+    }
+
+    #[test]
+    fn stream_len() {
+        // A common idiom to find the length of a stream, spoiled
+        let mut v = Vertigo::new(Cursor::new(TEXT), Spin::Offset(-20));
+        let length = v.seek(SeekFrom::End(0)).unwrap();
+        assert_eq!(length, u64::MAX - 7);
+    }
+}
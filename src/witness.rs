@@ -0,0 +1,318 @@
+use crate::{Outcome, Plan, Steps};
+use std::cell::RefCell;
+use std::fmt;
+use std::io;
+use std::io::Error;
+use std::rc::Rc;
+
+/// `Testimony` is what a [Witness] saw during one call
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Testimony<A> {
+    /// An [std::io::Write::write] call, and the bytes the Witness accepted
+    Write(Vec<u8>),
+    /// An [std::io::Write::flush] call
+    Flush,
+    /// A [std::fmt::Write::write_str] call, and its text
+    Text(String),
+    /// An [Extend::extend] call, and the items the Witness accepted
+    Extend(Vec<A>),
+    /// A call the Witness was scripted to refuse
+    Refused,
+}
+
+/// `Affidavit` is a shared handle on everything a [Witness] has seen, so that it can be
+/// inspected even after the Witness itself was given away or dropped.
+#[derive(Debug)]
+pub struct Affidavit<A> {
+    record: Rc<RefCell<Vec<Testimony<A>>>>,
+}
+
+impl<A> Clone for Affidavit<A> {
+    fn clone(&self) -> Self {
+        Affidavit {
+            record: self.record.clone(),
+        }
+    }
+}
+
+impl<A> Affidavit<A> {
+    /// Every [Testimony] so far, one for each call, in order
+    pub fn testimony(&self) -> Vec<Testimony<A>>
+    where
+        A: Clone,
+    {
+        self.record.borrow().clone()
+    }
+
+    /// All the bytes accepted by [std::io::Write::write], joined together
+    pub fn bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for testimony in self.record.borrow().iter() {
+            if let Testimony::Write(data) = testimony {
+                bytes.extend_from_slice(data);
+            }
+        }
+        bytes
+    }
+
+    /// All the text written with [std::fmt::Write], joined together
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for testimony in self.record.borrow().iter() {
+            if let Testimony::Text(s) = testimony {
+                text.push_str(s);
+            }
+        }
+        text
+    }
+
+    /// All the items accepted by [Extend::extend], in order
+    pub fn items(&self) -> Vec<A>
+    where
+        A: Clone,
+    {
+        let mut items = Vec::new();
+        for testimony in self.record.borrow().iter() {
+            if let Testimony::Extend(added) = testimony {
+                items.extend_from_slice(added);
+            }
+        }
+        items
+    }
+}
+
+/// `Witness` implements [std::io::Write], [std::fmt::Write] and [Extend], like a
+/// [BlackHole](crate::BlackHole), but it remembers everything it swallows, including where each
+/// call began and ended. An [Affidavit] from [Witness::affidavit] shows what it saw.
+///
+/// A Witness may also be given a script of [Outcome]s, one for each call, to make it misbehave.
+/// An `Ok(n)` Outcome accepts no more than `n` bytes from a write or `n` items from an extend,
+/// while an `Err` Outcome fails the call. Since [Extend] has no way to report failure, a refused
+/// extend panics. Once the script runs out, everything is accepted.
+///
+/// # Examples
+///
+/// ```
+/// # use misfortunate::{Testimony, Witness};
+/// use std::io::Write;
+/// let mut witness: Witness = Witness::new();
+/// let affidavit = witness.affidavit();
+/// witness.write_all(b"Hello, ").unwrap();
+/// witness.write_all(b"World").unwrap();
+/// witness.flush().unwrap();
+/// assert_eq!(affidavit.bytes(), b"Hello, World");
+/// assert_eq!(
+///     affidavit.testimony(),
+///     [
+///         Testimony::Write(b"Hello, ".to_vec()),
+///         Testimony::Write(b"World".to_vec()),
+///         Testimony::Flush,
+///     ]
+/// );
+/// ```
+///
+/// ```
+/// # use misfortunate::{Outcome, Testimony, Witness};
+/// use std::io::{ErrorKind, Write};
+/// let script = [Outcome::Ok(3), Outcome::Err(ErrorKind::Interrupted)];
+/// let mut witness: Witness = Witness::with_script(script);
+/// let affidavit = witness.affidavit();
+/// witness.write_all(b"Hello").unwrap();
+/// assert_eq!(affidavit.bytes(), b"Hello");
+/// assert_eq!(
+///     affidavit.testimony(),
+///     [
+///         Testimony::Write(b"Hel".to_vec()),
+///         Testimony::Refused,
+///         Testimony::Write(b"lo".to_vec()),
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Witness<A = u8> {
+    record: Rc<RefCell<Vec<Testimony<A>>>>,
+    script: Steps,
+}
+
+impl<A> Witness<A> {
+    /// Constructs a new `Witness` which accepts everything
+    pub fn new() -> Witness<A> {
+        Self::with_script([])
+    }
+
+    /// Constructs a new `Witness` which follows the `script`, given as a [Plan] or as
+    /// an array or [Vec] of [Outcome]s, and then accepts everything
+    pub fn with_script<P: Into<Plan>>(script: P) -> Witness<A> {
+        Witness {
+            record: Rc::new(RefCell::new(Vec::new())),
+            script: script.into().into_iter(),
+        }
+    }
+
+    /// An [Affidavit] showing what this `Witness` saw, now and later
+    pub fn affidavit(&self) -> Affidavit<A> {
+        Affidavit {
+            record: self.record.clone(),
+        }
+    }
+
+    fn cue(&mut self) -> Outcome {
+        self.script.next().unwrap_or(Outcome::Ok(usize::MAX))
+    }
+
+    fn testify(&self, testimony: Testimony<A>) {
+        self.record.borrow_mut().push(testimony);
+    }
+}
+
+impl<A> Default for Witness<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> io::Write for Witness<A> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.cue() {
+            Outcome::Ok(n) => {
+                let n = n.min(buf.len());
+                self.testify(Testimony::Write(buf[..n].to_vec()));
+                Ok(n)
+            }
+            Outcome::Err(kind) => {
+                self.testify(Testimony::Refused);
+                Err(Error::from(kind))
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.cue() {
+            Outcome::Ok(_) => {
+                self.testify(Testimony::Flush);
+                Ok(())
+            }
+            Outcome::Err(kind) => {
+                self.testify(Testimony::Refused);
+                Err(Error::from(kind))
+            }
+        }
+    }
+}
+
+impl<A> fmt::Write for Witness<A> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.cue() {
+            Outcome::Ok(_) => {
+                self.testify(Testimony::Text(s.to_owned()));
+                Ok(())
+            }
+            Outcome::Err(_) => {
+                self.testify(Testimony::Refused);
+                Err(fmt::Error)
+            }
+        }
+    }
+}
+
+impl<A> Extend<A> for Witness<A> {
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        match self.cue() {
+            Outcome::Ok(n) => {
+                let items = iter.into_iter().take(n).collect();
+                self.testify(Testimony::Extend(items));
+            }
+            Outcome::Err(kind) => {
+                self.testify(Testimony::Refused);
+                panic!("Witness refused to be extended: {kind:?}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{ErrorKind, Write as _};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[test]
+    fn create() {
+        let witness: Witness = Witness::default();
+        assert!(witness.affidavit().testimony().is_empty());
+    }
+
+    #[test]
+    fn outlives() {
+        let mut witness: Witness<char> = Witness::new();
+        let affidavit = witness.affidavit();
+        fmt::Write::write_fmt(&mut witness, format_args!("{}", "Rabbit")).unwrap();
+        witness.extend("abc".chars());
+        drop(witness);
+        assert_eq!(affidavit.text(), "Rabbit");
+        assert_eq!(affidavit.items(), ['a', 'b', 'c']);
+        assert!(affidavit.bytes().is_empty());
+    }
+
+    #[test]
+    fn boundaries() {
+        let mut witness: Witness = Witness::new();
+        witness.extend([1, 2]);
+        witness.extend([]);
+        witness.extend([3]);
+        assert_eq!(
+            witness.affidavit().testimony(),
+            [
+                Testimony::Extend(vec![1, 2]),
+                Testimony::Extend(vec![]),
+                Testimony::Extend(vec![3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn scripted_io() {
+        let script = [
+            Outcome::Ok(0),
+            Outcome::Ok(2),
+            Outcome::Err(ErrorKind::BrokenPipe),
+        ];
+        let mut witness: Witness = Witness::with_script(script);
+        let affidavit = witness.affidavit();
+        let err = witness.write_all(b"Hello").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+        witness.write_all(b"Hello").err().unwrap();
+        assert_eq!(affidavit.bytes(), b"He");
+        assert_eq!(
+            affidavit.testimony(),
+            [
+                Testimony::Write(vec![]),
+                Testimony::Write(b"He".to_vec()),
+                Testimony::Refused,
+            ]
+        );
+        witness.flush().unwrap();
+    }
+
+    #[test]
+    fn scripted_fmt() {
+        let mut witness: Witness = Witness::with_script([Outcome::Err(ErrorKind::Other)]);
+        assert!(fmt::Write::write_str(&mut witness, "Hello").is_err());
+        assert!(fmt::Write::write_str(&mut witness, "Hello").is_ok());
+        assert_eq!(witness.affidavit().text(), "Hello");
+    }
+
+    #[test]
+    fn scripted_extend() {
+        let script = [Outcome::Ok(2), Outcome::Err(ErrorKind::OutOfMemory)];
+        let mut witness: Witness<u32> = Witness::with_script(script);
+        let affidavit = witness.affidavit();
+        let mut numbers = 1..10;
+        witness.extend(numbers.by_ref());
+        assert_eq!(numbers.next(), Some(3));
+        let result = catch_unwind(AssertUnwindSafe(|| witness.extend(numbers.by_ref())));
+        assert!(result.is_err());
+        assert_eq!(affidavit.items(), [1, 2]);
+        assert_eq!(affidavit.testimony().last(), Some(&Testimony::Refused));
+    }
+}